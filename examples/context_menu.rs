@@ -0,0 +1,123 @@
+use bevy::{app::AppExit, color::palettes::css, prelude::*};
+use bevy_ui_nav::prelude::*;
+
+use example_utils::*;
+
+mod example_utils;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
+        .add_systems(Startup, startup)
+        .add_systems(
+            Update,
+            (
+                open_context_menus.run_if(on_event::<UiNavContextMenuEvent>),
+                handle_click_events.run_if(on_event::<UiNavClickEvent>),
+            )
+                .after(UiNavSet),
+        )
+        .run();
+}
+
+const CONTEXT_MENU_BG: Srgba = css::DIM_GRAY;
+
+#[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
+enum ButtonAction {
+    Quit,
+}
+
+/// Component for a file that has a context menu
+#[derive(Component)]
+struct File(&'static str);
+
+/// Component for the items in a file's context menu
+#[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
+enum FileAction {
+    Open,
+    Rename,
+    Delete,
+}
+
+/// Component for context menu items, referencing the file they act on
+#[derive(Component)]
+struct FileOwner(Entity);
+
+fn startup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    root_full_screen_centered(&mut commands, (), |p| {
+        menu_title(p, "Files");
+        text_widget(
+            p,
+            FontSize::Small,
+            "Right-click a file, or press the Menu key, Shift+F10 or the gamepad West button",
+        );
+        spawn_menu(true, false, p, ()).with_children(|p| {
+            for (i, name) in ["notes.txt", "photo.png", "song.ogg"].iter().enumerate() {
+                menu_button(p, *name, i == 0, false, false, File(name));
+            }
+            menu_button(p, "Quit", false, false, false, ButtonAction::Quit);
+        });
+    });
+}
+
+/// System that opens a context menu when one is requested for a file
+fn open_context_menus(
+    mut commands: Commands,
+    mut events: EventReader<UiNavContextMenuEvent>,
+    query: Query<(), With<File>>,
+) {
+    for event in events.read() {
+        if !query.contains(event.entity) {
+            continue;
+        }
+        commands
+            .spawn((
+                // Spawns the menu next to the file, or at the cursor when right-clicked. It is flipped when it would
+                // extend past the edge of the window.
+                NavContextMenu::bundle(event.entity, event.anchor),
+                BackgroundColor(CONTEXT_MENU_BG.into()),
+            ))
+            .with_children(|p| {
+                for (i, (text, action)) in [
+                    ("Open", FileAction::Open),
+                    ("Rename", FileAction::Rename),
+                    ("Delete", FileAction::Delete),
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    // The first item is prioritized so that it takes focus when the menu is opened
+                    menu_button(
+                        p,
+                        text,
+                        i == 0,
+                        false,
+                        false,
+                        (action, FileOwner(event.entity)),
+                    );
+                }
+            });
+    }
+}
+
+fn handle_click_events(
+    mut events: EventReader<UiNavClickEvent>,
+    query: Query<&ButtonAction>,
+    item_query: Query<(&FileAction, &FileOwner)>,
+    file_query: Query<&File>,
+    mut app_exit_writer: EventWriter<AppExit>,
+) {
+    for event in events.read() {
+        // NOTE: The context menu is closed automatically when one of its items is clicked
+        if let Ok((action, owner)) = item_query.get(event.0) {
+            if let Ok(file) = file_query.get(owner.0) {
+                println!("{action:?} {}", file.0);
+            }
+        }
+        if let Ok(ButtonAction::Quit) = query.get(event.0) {
+            app_exit_writer.write(AppExit::Success);
+        }
+    }
+}
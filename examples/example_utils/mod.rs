@@ -3,14 +3,6 @@
 use bevy::{color::palettes::css, ecs::relationship::RelatedSpawnerCommands, prelude::*};
 use bevy_ui_nav::prelude::*;
 
-pub struct ExampleUtilsPlugin;
-
-impl Plugin for ExampleUtilsPlugin {
-    fn build(&self, app: &mut App) {
-        app.add_systems(Update, button_style.after(UiNavSet));
-    }
-}
-
 pub const BUTTON_BG_DEFAULT: Srgba = css::DARK_GRAY;
 pub const BUTTON_BG_ACTIVE: Srgba = css::GRAY;
 pub const BUTTON_BG_PRESS: Srgba = css::BLACK;
@@ -19,9 +11,6 @@ pub const BUTTON_BG_DISABLED: Srgba = css::BEIGE;
 pub const FONT_SIZE_SM: f32 = 20.;
 pub const FONT_SIZE_LG: f32 = 40.;
 
-#[derive(Component)]
-pub struct StyledButton;
-
 pub enum FontSize {
     Small,
     Large,
@@ -36,6 +25,20 @@ impl From<FontSize> for f32 {
     }
 }
 
+/// Returns the style of buttons in the examples, which eases between colors when focus changes.
+pub fn button_nav_style() -> NavStyle {
+    NavStyle::new(
+        NavStyleValues::default()
+            .with_background_color(BUTTON_BG_DEFAULT)
+            .with_border_color(BUTTON_BG_DEFAULT),
+    )
+    .with_focus(NavStyleValues::default().with_background_color(BUTTON_BG_ACTIVE))
+    .with_focus_press(NavStyleValues::default().with_background_color(BUTTON_BG_PRESS))
+    .with_disabled(NavStyleValues::default().with_background_color(BUTTON_BG_DISABLED))
+    .with_hovered(NavStyleValues::default().with_border_color(Color::WHITE))
+    .with_transition(0.1, EaseFunction::QuadraticOut)
+}
+
 /// Utility that spawns a root node covering the entire screen, with content aligned to the center.
 pub fn root_full_screen_centered(
     commands: &mut Commands,
@@ -146,14 +149,7 @@ pub fn menu_buttoni(
     class(&mut node);
 
     spawner
-        .spawn((
-            StyledButton,
-            node,
-            BackgroundColor(BUTTON_BG_DEFAULT.into()),
-            BorderColor(BUTTON_BG_DEFAULT.into()),
-            Button,
-            extras,
-        ))
+        .spawn((button_nav_style(), node, Button, extras))
         .with_children(|p| {
             text_widget(p, FontSize::Small, text);
         })
@@ -164,30 +160,3 @@ pub fn menu_buttoni(
 pub fn menu_title(parent: &mut RelatedSpawnerCommands<ChildOf>, text: impl Into<String>) {
     text_widget(parent, FontSize::Large, text);
 }
-
-/// System that updates button colors when they change
-#[allow(clippy::type_complexity)]
-fn button_style(
-    mut query: Query<
-        (&Focusable, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Focusable>, With<StyledButton>),
-    >,
-) {
-    for (focusable, mut bg, mut border) in query.iter_mut() {
-        // background color
-        *bg = match focusable.state() {
-            FocusState::Focus => BUTTON_BG_ACTIVE,
-            FocusState::FocusPress => BUTTON_BG_PRESS,
-            FocusState::Disabled => BUTTON_BG_DISABLED,
-            _ => BUTTON_BG_DEFAULT,
-        }
-        .into();
-
-        // border color
-        *border = if focusable.is_hovered() {
-            Color::WHITE.into()
-        } else {
-            BUTTON_BG_DEFAULT.into()
-        };
-    }
-}
@@ -0,0 +1,104 @@
+use bevy::{
+    app::AppExit, color::palettes::css, ecs::relationship::RelatedSpawnerCommands, prelude::*,
+};
+use bevy_ui_nav::prelude::*;
+
+use example_utils::*;
+
+mod example_utils;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
+        .add_systems(Startup, startup)
+        .add_systems(
+            Update,
+            handle_click_events
+                .after(UiNavSet)
+                .run_if(on_event::<UiNavClickEvent>),
+        )
+        .run();
+}
+
+#[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
+enum ButtonAction {
+    Quit,
+}
+
+fn startup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    root_full_screen_centered(&mut commands, (), |p| {
+        menu_title(p, "Focus Cursor");
+        spawn_menu(true, false, p, ()).with_children(|p| {
+            for (i, text) in ["New Game", "Continue", "Settings"].iter().enumerate() {
+                spawn_plain_button(p, text, i == 0, ());
+            }
+            spawn_plain_button(p, "Quit", false, ButtonAction::Quit);
+        });
+    });
+
+    // The cursor is a root node drawn above the buttons, which moves to the focused button. It is hidden while the
+    // mouse is used, because hovered buttons already show a border.
+    commands.spawn((
+        NavFocusCursor::default()
+            .with_padding(Vec2::splat(4.))
+            .with_transition(0.2, EaseFunction::CubicOut)
+            .hidden_on_mouse(),
+        BorderColor(css::GOLD.into()),
+        BorderRadius::all(Val::Px(6.)),
+        Node {
+            position_type: PositionType::Absolute,
+            border: UiRect::all(Val::Px(3.)),
+            ..default()
+        },
+        GlobalZIndex(1),
+    ));
+}
+
+/// Spawns a button that is only styled when hovered, so that the cursor shows which button is focused
+fn spawn_plain_button(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    text: &str,
+    focus: bool,
+    extras: impl Bundle,
+) {
+    parent
+        .spawn((
+            Focusable::default().with_priority(focus),
+            Button,
+            NavStyle::new(
+                NavStyleValues::default()
+                    .with_background_color(BUTTON_BG_DEFAULT)
+                    .with_border_color(BUTTON_BG_DEFAULT),
+            )
+            .with_hovered(NavStyleValues::default().with_border_color(Color::WHITE)),
+            Node {
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                width: Val::Px(200.),
+                height: Val::Px(50.),
+                margin: UiRect::bottom(Val::Px(10.)),
+                border: UiRect::all(Val::Px(1.)),
+                ..default()
+            },
+            extras,
+        ))
+        .with_children(|p| {
+            text_widget(p, FontSize::Small, text);
+        });
+}
+
+fn handle_click_events(
+    mut events: EventReader<UiNavClickEvent>,
+    query: Query<&ButtonAction>,
+    mut app_exit_writer: EventWriter<AppExit>,
+) {
+    for button_action in events.nav_iter().in_query(&query) {
+        match *button_action {
+            ButtonAction::Quit => {
+                app_exit_writer.write(AppExit::Success);
+            }
+        }
+    }
+}
@@ -7,7 +7,8 @@ mod example_utils;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, BevyUiNavPlugin, ExampleUtilsPlugin))
+        // Press F3 to toggle the debug overlay
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin, UiNavDebugPlugin))
         .add_systems(Startup, startup)
         .run();
 }
@@ -55,7 +56,7 @@ pub fn spawn_grid_button(
 ) {
     spawner
         .spawn((
-            StyledButton,
+            button_nav_style(),
             Focusable::default(),
             Button,
             Node {
@@ -66,8 +67,6 @@ pub fn spawn_grid_button(
                 border: UiRect::all(Val::Px(1.)),
                 ..default()
             },
-            BackgroundColor(BUTTON_BG_DEFAULT.into()),
-            BorderColor(BUTTON_BG_DEFAULT.into()),
             extras,
         ))
         .with_children(|p| {
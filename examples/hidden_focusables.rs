@@ -7,7 +7,7 @@ mod example_utils;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, BevyUiNavPlugin, ExampleUtilsPlugin))
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
         .add_systems(Startup, startup)
         .add_systems(
             Update,
@@ -13,7 +13,7 @@ fn main() {
     let mut app = App::new();
 
     // add plugins
-    app.add_plugins((DefaultPlugins, BevyUiNavPlugin, ExampleUtilsPlugin));
+    app.add_plugins((DefaultPlugins, BevyUiNavPlugin));
     // initialize state and scoped entities
     app.init_state::<ScreenState>();
     // enable scoped entities. This allows the modal to be automatically de-spawned when we leave `ScreenState::Modal`
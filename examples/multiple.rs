@@ -7,13 +7,14 @@ mod example_utils;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, BevyUiNavPlugin, ExampleUtilsPlugin))
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
         .add_systems(Startup, startup)
         .add_systems(
             Update,
             (
                 handle_click_events.run_if(on_event::<UiNavClickEvent>),
                 handle_cancel_events.run_if(on_event::<UiNavCancelEvent>),
+                highlight_current_menu,
             )
                 .after(UiNavSet),
         )
@@ -217,3 +218,17 @@ fn handle_cancel_events(
         }
     }
 }
+
+/// System that dims the border of menus that are not the current menu
+fn highlight_current_menu(
+    mut query: Query<(&mut BorderColor, Has<CurrentNavMenu>), With<NavMenu>>,
+) {
+    for (mut border, is_current) in query.iter_mut() {
+        let color = if is_current {
+            Color::WHITE
+        } else {
+            Color::srgb(0.3, 0.3, 0.3)
+        };
+        border.set_if_neq(BorderColor(color));
+    }
+}
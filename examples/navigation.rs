@@ -7,7 +7,7 @@ mod example_utils;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, BevyUiNavPlugin, ExampleUtilsPlugin))
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
         .add_systems(Startup, startup)
         .run();
 }
@@ -120,7 +120,7 @@ pub fn button(
     let text: String = text.into();
     parent
         .spawn((
-            StyledButton,
+            button_nav_style(),
             Focusable::default(),
             Button,
             Node {
@@ -131,8 +131,6 @@ pub fn button(
                 border: UiRect::all(Val::Px(1.)),
                 ..default()
             },
-            BackgroundColor(BUTTON_BG_DEFAULT.into()),
-            BorderColor(BUTTON_BG_DEFAULT.into()),
             Name::new(text.clone()),
         ))
         .with_children(|p| {
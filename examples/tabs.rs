@@ -0,0 +1,136 @@
+use bevy::{
+    app::AppExit, color::palettes::css, ecs::relationship::RelatedSpawnerCommands, prelude::*,
+};
+use bevy_ui_nav::prelude::*;
+
+use example_utils::*;
+
+mod example_utils;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
+        .add_systems(Startup, startup)
+        .add_systems(
+            Update,
+            (
+                tab_style,
+                print_tab_changes.run_if(on_event::<UiNavTabChangedEvent>),
+                handle_click_events.run_if(on_event::<UiNavClickEvent>),
+            )
+                .after(UiNavSet),
+        )
+        .run();
+}
+
+const TAB_SELECTED: Srgba = css::STEEL_BLUE;
+
+#[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
+enum ButtonAction {
+    Quit,
+}
+
+/// Utility that spawns a tab panel containing a menu with a few buttons.
+fn tab_panel(parent: &mut RelatedSpawnerCommands<ChildOf>, name: &str) -> Entity {
+    parent
+        .spawn((
+            NavMenu::default(),
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.)),
+                ..default()
+            },
+        ))
+        .with_children(|p| {
+            for i in 1..=3 {
+                menu_button(p, format!("{name} {i}"), false, false, false, ());
+            }
+            menu_button(p, "Quit", false, false, false, ButtonAction::Quit);
+        })
+        .id()
+}
+
+fn startup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    root_full_screen_centered(&mut commands, (), |p| {
+        text_widget(
+            p,
+            FontSize::Small,
+            "Switch tabs with the gamepad shoulder buttons or Ctrl+Tab/Ctrl+Shift+Tab",
+        );
+        p.spawn((
+            // `NavTabs` hides the panels of tabs that are not selected
+            NavTabs::default().with_wrap(true),
+            Node {
+                // The tab bar is spawned after the panels, so reverse the order to show it at the top
+                flex_direction: FlexDirection::ColumnReverse,
+                align_items: AlignItems::Center,
+                margin: UiRect::top(Val::Px(20.)),
+                ..default()
+            },
+        ))
+        .with_children(|p| {
+            let names = ["Video", "Audio", "Controls"];
+            let panels = names.map(|name| tab_panel(p, name));
+
+            // spawn the tab bar
+            p.spawn((
+                NavMenu::default().prioritized(),
+                Node {
+                    column_gap: Val::Px(10.),
+                    ..default()
+                },
+            ))
+            .with_children(|p| {
+                for (i, (name, panel)) in names.iter().zip(panels).enumerate() {
+                    menu_buttoni(
+                        p,
+                        *name,
+                        (
+                            NavTab::new(panel),
+                            Focusable::default().with_priority(i == 0),
+                        ),
+                        |node| node.width = Val::Px(150.),
+                    );
+                }
+            });
+        });
+    });
+}
+
+/// System that outlines the selected tab
+fn tab_style(mut commands: Commands, query: Query<(Entity, &NavTab), Changed<NavTab>>) {
+    for (entity, tab) in query.iter() {
+        if tab.is_selected() {
+            commands.entity(entity).insert(Outline::new(
+                Val::Px(3.),
+                Val::Px(2.),
+                TAB_SELECTED.into(),
+            ));
+        } else {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+}
+
+fn print_tab_changes(mut events: EventReader<UiNavTabChangedEvent>) {
+    for event in events.read() {
+        println!("{event:?}");
+    }
+}
+
+fn handle_click_events(
+    mut events: EventReader<UiNavClickEvent>,
+    query: Query<&ButtonAction>,
+    mut app_exit_writer: EventWriter<AppExit>,
+) {
+    for event in events.nav_iter().in_query(&query) {
+        match *event {
+            ButtonAction::Quit => {
+                app_exit_writer.write(AppExit::Success);
+            }
+        }
+    }
+}
@@ -1,13 +1,4 @@
-use bevy::{
-    app::AppExit,
-    color::palettes::css,
-    ecs::relationship::RelatedSpawnerCommands,
-    input::{
-        keyboard::{Key, KeyboardInput},
-        ButtonState,
-    },
-    prelude::*,
-};
+use bevy::{app::AppExit, color::palettes::css, prelude::*};
 use bevy_ui_nav::prelude::*;
 
 use example_utils::*;
@@ -16,7 +7,7 @@ mod example_utils;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, BevyUiNavPlugin, ExampleUtilsPlugin))
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
         .init_resource::<GameData>()
         .add_systems(Startup, startup)
         .add_systems(
@@ -24,10 +15,8 @@ fn main() {
             (
                 text_control_style,
                 debug_cancel_events.run_if(on_event::<UiNavCancelEvent>),
-                (handle_button_click_events, handle_text_control_click_events)
-                    .run_if(on_event::<UiNavClickEvent>),
-                listen_received_character_events.run_if(on_event::<KeyboardInput>),
-                update_text_on_change,
+                handle_button_click_events.run_if(on_event::<UiNavClickEvent>),
+                handle_text_submit_events.run_if(on_event::<UiNavTextInputSubmitEvent>),
                 update_title_label.run_if(resource_changed::<GameData>),
             )
                 .after(UiNavSet),
@@ -50,62 +39,12 @@ struct GameData {
 #[derive(Component)]
 struct TitleLabel;
 
-#[derive(Component, Default, Debug)]
-struct TextControl(String);
-
-#[derive(Component, Default, Debug, PartialEq, Eq)]
-enum TextControlStatus {
-    #[default]
-    InActive,
-    Active,
-}
-
 #[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
 enum ButtonAction {
     Reset,
     Quit,
 }
 
-/// Utility that spawns a text control.
-fn spawn_text_control(
-    parent: &mut RelatedSpawnerCommands<ChildOf>,
-    text: impl Into<String>,
-    focus: bool,
-    extras: impl Bundle,
-) -> Entity {
-    parent
-        .spawn((
-            if focus {
-                Focusable::prioritized()
-            } else {
-                Focusable::default()
-            },
-            Node {
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::Center,
-                width: Val::Px(200.),
-                height: Val::Px(50.),
-                margin: UiRect::bottom(Val::Px(10.)),
-                border: UiRect::all(Val::Px(1.)),
-                ..default()
-            },
-            BackgroundColor(TEXT_CONTROL_BG_DEFAULT.into()),
-            BorderColor(TEXT_CONTROL_BORDER_DEFAULT.into()),
-            Interaction::default(),
-            TextControl::default(),
-            TextControlStatus::InActive,
-            extras,
-        ))
-        .with_children(|p| {
-            p.spawn((
-                Text::new(text),
-                TextColor(Color::BLACK),
-                TextFont::from_font_size(20.),
-            ));
-        })
-        .id()
-}
-
 fn startup(mut commands: Commands) {
     commands.spawn(Camera2d);
 
@@ -116,8 +55,31 @@ fn startup(mut commands: Commands) {
                 p.spawn((TitleLabel, TextSpan::new("")));
             });
 
-            // text control
-            spawn_text_control(p, "", true, ());
+            // text control, limited to 16 letters, digits or spaces
+            p.spawn((
+                NavTextInput::default()
+                    .with_max_length(16)
+                    .with_filter(|c| c.is_alphanumeric() || c == ' '),
+                Focusable::prioritized(),
+                Node {
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    width: Val::Px(200.),
+                    height: Val::Px(50.),
+                    margin: UiRect::bottom(Val::Px(10.)),
+                    border: UiRect::all(Val::Px(1.)),
+                    ..default()
+                },
+                BackgroundColor(TEXT_CONTROL_BG_DEFAULT.into()),
+                BorderColor(TEXT_CONTROL_BORDER_DEFAULT.into()),
+            ))
+            .with_children(|p| {
+                p.spawn((
+                    Text::new(""),
+                    TextColor(Color::BLACK),
+                    TextFont::from_font_size(20.),
+                ));
+            });
 
             // Save and cancel buttons
             menu_button(p, "Reset", false, false, false, ButtonAction::Reset);
@@ -132,16 +94,16 @@ fn text_control_style(
     mut query: Query<
         (
             &Focusable,
+            &NavTextInput,
             &mut BackgroundColor,
             &mut BorderColor,
-            &TextControlStatus,
         ),
-        Or<(Changed<Focusable>, Changed<TextControlStatus>)>,
+        Or<(Changed<Focusable>, Changed<NavTextInput>)>,
     >,
 ) {
-    for (focusable, mut bg, mut border, status) in query.iter_mut() {
+    for (focusable, text_input, mut bg, mut border) in query.iter_mut() {
         // Update background color
-        *bg = if *status == TextControlStatus::Active {
+        *bg = if text_input.is_editing() {
             TEXT_CONTROL_BG_ACTIVE
         } else {
             TEXT_CONTROL_BG_DEFAULT
@@ -149,7 +111,7 @@ fn text_control_style(
         .into();
 
         // Update border color
-        *border = if *status == TextControlStatus::Active {
+        *border = if text_input.is_editing() {
             TEXT_CONTROL_BORDER_ACTIVE
         } else if focusable.is_hovered() || focusable.state().active() {
             TEXT_CONTROL_BORDER_HOVER
@@ -165,7 +127,7 @@ fn handle_button_click_events(
     query: Query<&ButtonAction, (With<Focusable>, With<Button>)>,
     mut app_exit_writer: EventWriter<AppExit>,
     mut game_data: ResMut<GameData>,
-    mut text_control_query: Query<&mut TextControl>,
+    mut text_input_query: Query<&mut NavTextInput>,
 ) {
     for event in events.read() {
         if let Ok(button_action) = query.get(event.0) {
@@ -176,8 +138,8 @@ fn handle_button_click_events(
                 }
                 ButtonAction::Reset => {
                     game_data.name = "".to_string();
-                    for mut text_control in text_control_query.iter_mut() {
-                        text_control.0 = "".to_string();
+                    for mut text_input in text_input_query.iter_mut() {
+                        text_input.set_value("");
                     }
                 }
             };
@@ -185,25 +147,13 @@ fn handle_button_click_events(
     }
 }
 
-/// System that handles click events on a text control
-fn handle_text_control_click_events(
-    mut events: EventReader<UiNavClickEvent>,
-    mut query: Query<&mut TextControlStatus>,
-    mut nav_request_writer: EventWriter<NavRequest>,
+/// System that stores the submitted name in `GameData`
+fn handle_text_submit_events(
+    mut events: EventReader<UiNavTextInputSubmitEvent>,
+    mut game_data: ResMut<GameData>,
 ) {
     for event in events.read() {
-        if let Ok(mut status) = query.get_mut(event.0) {
-            match *status {
-                TextControlStatus::InActive => {
-                    *status = TextControlStatus::Active;
-                    nav_request_writer.write(NavRequest::Lock);
-                }
-                TextControlStatus::Active => {
-                    *status = TextControlStatus::InActive;
-                    nav_request_writer.write(NavRequest::Unlock);
-                }
-            }
-        }
+        game_data.name.clone_from(&event.value);
     }
 }
 
@@ -214,97 +164,6 @@ fn update_title_label(game_data: Res<GameData>, mut query: Query<&mut TextSpan,
     }
 }
 
-/// System that listens for characer key presses in the text control
-fn listen_received_character_events(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut events: EventReader<KeyboardInput>,
-    mut query: Query<(&mut TextControl, &mut TextControlStatus, &Children)>,
-    mut text_query: Query<&mut Text>,
-    mut nav_request_writer: EventWriter<NavRequest>,
-    mut game_data: ResMut<GameData>,
-    button_query: Query<(Entity, &ButtonAction)>,
-) {
-    for event in events.read() {
-        if event.state == ButtonState::Pressed {
-            for (mut text_control, mut status, children) in query.iter_mut() {
-                if *status != TextControlStatus::Active {
-                    continue;
-                }
-
-                // track whether we handled the key press
-                let is_changed = match &event.logical_key {
-                    Key::Character(char) => {
-                        text_control.0.push_str(char);
-                        true
-                    }
-                    Key::Backspace => {
-                        text_control.0.pop();
-                        true
-                    }
-                    Key::Enter => {
-                        if !keys.just_pressed(KeyCode::Enter) {
-                            false
-                        } else {
-                            *status = TextControlStatus::InActive;
-                            game_data.name.clone_from(&text_control.0);
-                            // unlock navigation
-                            nav_request_writer.write(NavRequest::Unlock);
-                            // set focus on the submit button
-                            if let Some(submit_button) = button_query
-                                .iter()
-                                .find(|(_, action)| matches!(action, ButtonAction::Reset))
-                                .map(|(e, _)| e)
-                            {
-                                nav_request_writer.write(NavRequest::SetFocus {
-                                    entity: submit_button,
-                                    interaction_type: UiNavInteractionType::Manual,
-                                });
-                            }
-                            true
-                        }
-                    }
-                    Key::Space => {
-                        text_control.0.push(' ');
-                        true
-                    }
-                    Key::Escape => {
-                        *status = TextControlStatus::InActive;
-                        nav_request_writer.write(NavRequest::Unlock);
-                        text_control.0.clone_from(&game_data.name);
-                        true
-                    }
-                    _ => false,
-                };
-
-                // Update the text content instantly
-                if is_changed {
-                    for child in children.iter() {
-                        if let Ok(mut text) = text_query.get_mut(child) {
-                            text.0.clone_from(&text_control.0);
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
-
-/// System that updates the content of a text control when it is changed.
-/// NOTE: This is not important in this example, as we update the value instantly when we receive a character keypress.
-/// However, this system would allow the text to update if you manually changed `TextControl::value`.
-fn update_text_on_change(
-    query: Query<(&TextControl, &Children), Changed<TextControl>>,
-    mut text_query: Query<&mut Text>,
-) {
-    for (text_control, children) in query.iter() {
-        for child in children.iter() {
-            if let Ok(mut text) = text_query.get_mut(child) {
-                text.0.clone_from(&text_control.0);
-            }
-        }
-    }
-}
-
 /// System that prints [`UiNavCancelEvent`] events to console.
 fn debug_cancel_events(mut events: EventReader<UiNavCancelEvent>) {
     for event in events.read() {
@@ -0,0 +1,96 @@
+use bevy::{app::AppExit, color::palettes::css, prelude::*};
+use bevy_ui_nav::prelude::*;
+
+use example_utils::*;
+
+mod example_utils;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
+        .add_systems(Startup, startup)
+        .add_systems(
+            Update,
+            handle_click_events
+                .after(UiNavSet)
+                .run_if(on_event::<UiNavClickEvent>),
+        )
+        .run();
+}
+
+#[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
+enum ButtonAction {
+    Quit,
+}
+
+fn startup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    root_full_screen_centered(&mut commands, (), |p| {
+        menu_title(p, "Tooltips");
+        spawn_menu(true, false, p, ()).with_children(|p| {
+            menu_button(
+                p,
+                "New Game",
+                true,
+                false,
+                false,
+                NavTooltip::new("Start a new game from the beginning"),
+            );
+            menu_button(
+                p,
+                "Continue",
+                false,
+                false,
+                false,
+                NavTooltip::new("Continue from your last save"),
+            );
+            menu_button(
+                p,
+                "Quit",
+                false,
+                false,
+                false,
+                (ButtonAction::Quit, NavTooltip::new("Exit to the desktop")),
+            );
+        });
+
+        // A description panel, which shows the tooltip of the hovered or focused button
+        p.spawn((
+            NavTooltipDisplay::PANEL,
+            Text::default(),
+            TextColor(Color::WHITE),
+            TextFont::from_font_size(FONT_SIZE_SM),
+        ));
+    });
+
+    // A tooltip placed next to the hovered or focused button
+    commands
+        .spawn((
+            NavTooltipDisplay::ANCHORED,
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::all(Val::Px(5.)),
+                ..default()
+            },
+            BackgroundColor(css::DIM_GRAY.into()),
+            GlobalZIndex(1),
+        ))
+        .with_children(|p| {
+            text_widget(p, FontSize::Small, "");
+        });
+}
+
+fn handle_click_events(
+    mut events: EventReader<UiNavClickEvent>,
+    query: Query<&ButtonAction>,
+    mut app_exit_writer: EventWriter<AppExit>,
+) {
+    for button_action in events.nav_iter().in_query(&query) {
+        match *button_action {
+            ButtonAction::Quit => {
+                app_exit_writer.write(AppExit::Success);
+            }
+        }
+    }
+}
@@ -7,7 +7,7 @@ mod example_utils;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, BevyUiNavPlugin, ExampleUtilsPlugin))
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
         .init_state::<AppState>()
         .enable_state_scoped_entities::<AppState>()
         .init_state::<PlayState>()
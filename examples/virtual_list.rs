@@ -0,0 +1,108 @@
+use bevy::{app::AppExit, prelude::*};
+use bevy_ui_nav::prelude::*;
+
+use example_utils::*;
+
+mod example_utils;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
+        .add_systems(Startup, startup)
+        .add_systems(
+            Update,
+            (
+                setup_rows,
+                bind_rows.run_if(on_event::<UiNavVirtualRowBoundEvent>),
+                handle_click_events.run_if(on_event::<UiNavClickEvent>),
+            )
+                .chain()
+                .after(UiNavSet),
+        )
+        .run();
+}
+
+const ITEM_COUNT: usize = 10_000;
+const VISIBLE_ROWS: usize = 10;
+const ROW_HEIGHT: f32 = 30.;
+
+#[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
+enum ButtonAction {
+    Quit,
+}
+
+fn startup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    root_full_screen_centered(&mut commands, (), |p| {
+        menu_title(p, format!("{ITEM_COUNT} Servers"));
+        spawn_menu(true, false, p, ()).with_children(|p| {
+            // Only `VISIBLE_ROWS` focusable rows are spawned, no matter how many items there are
+            p.spawn((
+                NavVirtualList::new(ITEM_COUNT, VISIBLE_ROWS, ROW_HEIGHT),
+                Node {
+                    width: Val::Px(300.),
+                    flex_direction: FlexDirection::Column,
+                    margin: UiRect::bottom(Val::Px(10.)),
+                    ..default()
+                },
+            ));
+            menu_button(p, "Quit", false, false, false, ButtonAction::Quit);
+        });
+    });
+}
+
+/// Returns the label for the item at `index`.
+fn row_label(index: Option<usize>) -> String {
+    index.map_or_else(String::new, |i| format!("Server #{}", i + 1))
+}
+
+/// System that adds a label to newly spawned rows
+fn setup_rows(
+    mut commands: Commands,
+    query: Query<(Entity, &NavVirtualRow), Added<NavVirtualRow>>,
+) {
+    for (entity, row) in query.iter() {
+        commands
+            .entity(entity)
+            .insert(
+                NavStyle::new(NavStyleValues::default().with_background_color(BUTTON_BG_DEFAULT))
+                    .with_focus(NavStyleValues::default().with_background_color(BUTTON_BG_ACTIVE)),
+            )
+            .with_children(|p| {
+                text_widget(p, FontSize::Small, row_label(row.index()));
+            });
+    }
+}
+
+/// System that updates row labels when they are bound to a different item
+fn bind_rows(
+    mut events: EventReader<UiNavVirtualRowBoundEvent>,
+    children_query: Query<&Children>,
+    mut text_query: Query<&mut Text>,
+) {
+    for event in events.read() {
+        let mut iter = text_query.iter_many_mut(children_query.iter_descendants(event.row));
+        while let Some(mut text) = iter.fetch_next() {
+            text.0 = row_label(event.index);
+        }
+    }
+}
+
+fn handle_click_events(
+    mut events: EventReader<UiNavClickEvent>,
+    query: Query<&ButtonAction>,
+    row_query: Query<&NavVirtualRow>,
+    mut app_exit_writer: EventWriter<AppExit>,
+) {
+    for event in events.read() {
+        if let Ok(row) = row_query.get(event.0) {
+            if let Some(index) = row.index() {
+                println!("Joining server #{}", index + 1);
+            }
+        }
+        if let Ok(ButtonAction::Quit) = query.get(event.0) {
+            app_exit_writer.write(AppExit::Success);
+        }
+    }
+}
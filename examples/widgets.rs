@@ -0,0 +1,345 @@
+use bevy::{
+    app::AppExit, color::palettes::css, ecs::relationship::RelatedSpawnerCommands, prelude::*,
+};
+use bevy_ui_nav::prelude::*;
+
+use example_utils::*;
+
+mod example_utils;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, BevyUiNavPlugin))
+        .add_systems(Startup, startup)
+        .add_systems(
+            Update,
+            (
+                widget_style,
+                update_slider_fill,
+                update_toggle_style,
+                update_radio_style,
+                print_slider_changes.run_if(on_event::<UiNavSliderChangedEvent>),
+                print_toggle_changes.run_if(on_event::<UiNavToggleChangedEvent>),
+                print_radio_changes.run_if(on_event::<UiNavRadioGroupChangedEvent>),
+                print_cycler_changes.run_if(on_event::<UiNavCyclerChangedEvent>),
+                print_dropdown_changes.run_if(on_event::<UiNavDropdownChangedEvent>),
+                dropdown_option_style,
+                handle_click_events.run_if(on_event::<UiNavClickEvent>),
+            )
+                .after(UiNavSet),
+        )
+        .run();
+}
+
+const WIDGET_BORDER_DEFAULT: Srgba = css::DARK_GRAY;
+const WIDGET_BORDER_ACTIVE: Srgba = css::WHITE;
+const SLIDER_FILL: Srgba = css::STEEL_BLUE;
+const CHECKED: Srgba = css::STEEL_BLUE;
+const UNCHECKED: Srgba = css::BLACK;
+
+#[derive(Component, PartialEq, Eq, Clone, Copy, Debug)]
+enum ButtonAction {
+    Quit,
+}
+
+/// Marker for the fill bar inside a slider
+#[derive(Component)]
+struct SliderFill;
+
+/// Marker for widgets styled by `widget_style`
+#[derive(Component)]
+struct StyledWidget;
+
+/// Utility that spawns a labelled settings row.
+fn settings_row(
+    parent: &mut RelatedSpawnerCommands<ChildOf>,
+    label: impl Into<String>,
+    widget: impl FnOnce(&mut RelatedSpawnerCommands<ChildOf>),
+) {
+    parent
+        .spawn(Node {
+            width: Val::Px(400.),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::SpaceBetween,
+            margin: UiRect::bottom(Val::Px(10.)),
+            ..default()
+        })
+        .with_children(|p| {
+            text_widget(p, FontSize::Small, label);
+            widget(p);
+        });
+}
+
+/// Utility that spawns a horizontal slider with a fill bar.
+fn slider(parent: &mut RelatedSpawnerCommands<ChildOf>, slider: NavSlider, focus: bool) {
+    parent
+        .spawn((
+            slider,
+            Focusable::default().with_priority(focus),
+            StyledWidget,
+            Node {
+                width: Val::Px(200.),
+                height: Val::Px(30.),
+                border: UiRect::all(Val::Px(2.)),
+                ..default()
+            },
+            BackgroundColor(css::BLACK.into()),
+            BorderColor(WIDGET_BORDER_DEFAULT.into()),
+        ))
+        .with_children(|p| {
+            p.spawn((
+                SliderFill,
+                Node {
+                    height: Val::Percent(100.),
+                    ..default()
+                },
+                BackgroundColor(SLIDER_FILL.into()),
+            ));
+        });
+}
+
+/// Utility that spawns a square checkbox.
+fn checkbox(parent: &mut RelatedSpawnerCommands<ChildOf>, checked: bool) {
+    parent.spawn((
+        NavToggle::new(checked),
+        StyledWidget,
+        Node {
+            width: Val::Px(30.),
+            height: Val::Px(30.),
+            border: UiRect::all(Val::Px(2.)),
+            ..default()
+        },
+        BackgroundColor(UNCHECKED.into()),
+        BorderColor(WIDGET_BORDER_DEFAULT.into()),
+    ));
+}
+
+/// Utility that spawns a row of labelled radio buttons.
+fn radio_group(parent: &mut RelatedSpawnerCommands<ChildOf>, options: &[&str], selected: usize) {
+    parent
+        .spawn((
+            NavRadioGroup::default(),
+            Node {
+                column_gap: Val::Px(5.),
+                ..default()
+            },
+        ))
+        .with_children(|p| {
+            for (i, option) in options.iter().enumerate() {
+                let button = if i == selected {
+                    NavRadioButton::selected()
+                } else {
+                    NavRadioButton::default()
+                };
+                p.spawn((
+                    button,
+                    StyledWidget,
+                    Node {
+                        padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                        border: UiRect::all(Val::Px(2.)),
+                        ..default()
+                    },
+                    BackgroundColor(UNCHECKED.into()),
+                    BorderColor(WIDGET_BORDER_DEFAULT.into()),
+                ))
+                .with_children(|p| {
+                    text_widget(p, FontSize::Small, *option);
+                });
+            }
+        });
+}
+
+/// Utility that spawns an option cycler.
+fn cycler(parent: &mut RelatedSpawnerCommands<ChildOf>, cycler: NavCycler) {
+    parent
+        .spawn((
+            cycler,
+            StyledWidget,
+            Node {
+                width: Val::Px(200.),
+                justify_content: JustifyContent::Center,
+                padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                border: UiRect::all(Val::Px(2.)),
+                ..default()
+            },
+            BackgroundColor(css::BLACK.into()),
+            BorderColor(WIDGET_BORDER_DEFAULT.into()),
+        ))
+        .with_children(|p| {
+            text_widget(p, FontSize::Small, "");
+        });
+}
+
+/// Utility that spawns a dropdown.
+fn dropdown(parent: &mut RelatedSpawnerCommands<ChildOf>, dropdown: NavDropdown) {
+    parent
+        .spawn((
+            dropdown,
+            StyledWidget,
+            Node {
+                width: Val::Px(200.),
+                padding: UiRect::axes(Val::Px(8.), Val::Px(4.)),
+                border: UiRect::all(Val::Px(2.)),
+                ..default()
+            },
+            BackgroundColor(css::BLACK.into()),
+            BorderColor(WIDGET_BORDER_DEFAULT.into()),
+        ))
+        .with_children(|p| {
+            text_widget(p, FontSize::Small, "");
+        });
+}
+
+fn startup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    root_full_screen_centered(&mut commands, (), |p| {
+        menu_title(p, "Settings");
+        spawn_menu(true, false, p, ()).with_children(|p| {
+            settings_row(p, "Volume", |p| {
+                slider(p, NavSlider::new(0., 100., 10., 80.), true);
+            });
+            settings_row(p, "Sensitivity", |p| {
+                slider(p, NavSlider::new(0.1, 2., 0.1, 1.), false);
+            });
+            settings_row(p, "Quality", |p| {
+                cycler(p, NavCycler::new(["Low", "Medium", "High"]).with_index(1));
+            });
+            settings_row(p, "Language", |p| {
+                cycler(
+                    p,
+                    NavCycler::new(["English", "French", "German"]).wrapping(),
+                );
+            });
+            settings_row(p, "Resolution", |p| {
+                dropdown(
+                    p,
+                    NavDropdown::new(["1280x720", "1920x1080", "2560x1440", "3840x2160"])
+                        .with_selected(1),
+                );
+            });
+            settings_row(p, "Fullscreen", |p| {
+                checkbox(p, false);
+            });
+            settings_row(p, "Difficulty", |p| {
+                radio_group(p, &["Easy", "Normal", "Hard"], 1);
+            });
+            menu_button(p, "Quit", false, false, false, ButtonAction::Quit);
+        });
+    });
+}
+
+/// System that highlights the border of focused widgets
+#[allow(clippy::type_complexity)]
+fn widget_style(
+    mut query: Query<(&Focusable, &mut BorderColor), (Changed<Focusable>, With<StyledWidget>)>,
+) {
+    for (focusable, mut border) in query.iter_mut() {
+        *border = if focusable.state().active() || focusable.is_hovered() {
+            WIDGET_BORDER_ACTIVE
+        } else {
+            WIDGET_BORDER_DEFAULT
+        }
+        .into();
+    }
+}
+
+/// System that resizes slider fill bars when the slider value changes
+fn update_slider_fill(
+    query: Query<(&NavSlider, &Children), Changed<NavSlider>>,
+    mut fill_query: Query<&mut Node, With<SliderFill>>,
+) {
+    for (slider, children) in query.iter() {
+        let mut iter = fill_query.iter_many_mut(children);
+        while let Some(mut node) = iter.fetch_next() {
+            node.width = Val::Percent(slider.fraction() * 100.);
+        }
+    }
+}
+
+/// System that fills checked toggles
+fn update_toggle_style(mut query: Query<(&NavToggle, &mut BackgroundColor), Changed<NavToggle>>) {
+    for (toggle, mut bg) in query.iter_mut() {
+        *bg = if toggle.is_checked() {
+            CHECKED
+        } else {
+            UNCHECKED
+        }
+        .into();
+    }
+}
+
+/// System that fills the selected radio buttons
+fn update_radio_style(
+    mut query: Query<(&NavRadioButton, &mut BackgroundColor), Changed<NavRadioButton>>,
+) {
+    for (button, mut bg) in query.iter_mut() {
+        *bg = if button.is_selected() {
+            CHECKED
+        } else {
+            UNCHECKED
+        }
+        .into();
+    }
+}
+
+fn print_toggle_changes(mut events: EventReader<UiNavToggleChangedEvent>) {
+    for event in events.read() {
+        println!("{event:?}");
+    }
+}
+
+fn print_radio_changes(mut events: EventReader<UiNavRadioGroupChangedEvent>) {
+    for event in events.read() {
+        println!("{event:?}");
+    }
+}
+
+/// System that highlights the focused option in dropdown popups
+#[allow(clippy::type_complexity)]
+fn dropdown_option_style(
+    mut query: Query<
+        (&Focusable, &mut BackgroundColor),
+        (Changed<Focusable>, With<NavDropdownOption>),
+    >,
+) {
+    for (focusable, mut bg) in query.iter_mut() {
+        *bg = if focusable.state().active() || focusable.is_hovered() {
+            SLIDER_FILL.into()
+        } else {
+            Color::NONE.into()
+        };
+    }
+}
+
+fn print_dropdown_changes(mut events: EventReader<UiNavDropdownChangedEvent>) {
+    for event in events.read() {
+        println!("{event:?}");
+    }
+}
+
+fn print_cycler_changes(mut events: EventReader<UiNavCyclerChangedEvent>) {
+    for event in events.read() {
+        println!("{event:?}");
+    }
+}
+
+fn print_slider_changes(mut events: EventReader<UiNavSliderChangedEvent>) {
+    for event in events.read() {
+        println!("{event:?}");
+    }
+}
+
+fn handle_click_events(
+    mut events: EventReader<UiNavClickEvent>,
+    query: Query<&ButtonAction>,
+    mut app_exit_writer: EventWriter<AppExit>,
+) {
+    for event in events.nav_iter().in_query(&query) {
+        match *event {
+            ButtonAction::Quit => {
+                app_exit_writer.write(AppExit::Success);
+            }
+        }
+    }
+}
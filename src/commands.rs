@@ -0,0 +1,220 @@
+use bevy::prelude::*;
+
+use crate::{
+    components::*,
+    events::NavRequest,
+    types::{NavLockReason, NavLockScope, UiNavInteractionType},
+};
+
+/// Number of frames a focus request waits for its target before it is dropped.
+const PENDING_FOCUS_MAX_FRAMES: u32 = 60;
+
+/// Resource holding a focus request that is waiting for its target to be able to receive focus.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct UiNavPendingFocus {
+    entity: Option<Entity>,
+    /// The number of frames the request has waited for
+    frames: u32,
+}
+
+/// Extension trait for sending navigation commands from [`Commands`].
+pub trait UiNavCommandsExt<'w, 's> {
+    /// Returns the navigation commands.
+    fn ui_nav<'a>(&'a mut self) -> UiNavCommands<'a, 'w, 's>;
+}
+
+impl<'w, 's> UiNavCommandsExt<'w, 's> for Commands<'w, 's> {
+    fn ui_nav<'a>(&'a mut self) -> UiNavCommands<'a, 'w, 's> {
+        UiNavCommands { commands: self }
+    }
+}
+
+/// Navigation commands returned by [`UiNavCommandsExt::ui_nav`].
+///
+/// Unlike sending a [`NavRequest::SetFocus`] directly, focus commands wait until their target has been set up and laid
+/// out, so they can be used in the same frame the target is spawned. Only the last focus command is applied.
+pub struct UiNavCommands<'a, 'w, 's> {
+    commands: &'a mut Commands<'w, 's>,
+}
+
+impl UiNavCommands<'_, '_, '_> {
+    /// Sets focus on a focusable once it can receive focus, making its menu the current menu.
+    pub fn focus(&mut self, entity: Entity) -> &mut Self {
+        self.commands.queue(move |world: &mut World| {
+            *world.resource_mut::<UiNavPendingFocus>() = UiNavPendingFocus {
+                entity: Some(entity),
+                frames: 0,
+            };
+        });
+        self
+    }
+
+    /// Makes a menu the current menu once it has a focusable that can receive focus.
+    pub fn focus_menu(&mut self, menu: Entity) -> &mut Self {
+        self.focus(menu)
+    }
+
+    /// Locks navigation. No effect if already locked.
+    pub fn lock(&mut self) -> &mut Self {
+        self.commands.send_event(NavRequest::Lock);
+        self
+    }
+
+    /// Unlocks navigation. No effect if already unlocked.
+    pub fn unlock(&mut self) -> &mut Self {
+        self.commands.send_event(NavRequest::Unlock);
+        self
+    }
+
+    /// Adds a lock held by `reason`, blocking the input in `scope` until every lock is released.
+    pub fn lock_with(&mut self, reason: NavLockReason, scope: NavLockScope) -> &mut Self {
+        self.commands
+            .send_event(NavRequest::LockWith { reason, scope });
+        self
+    }
+
+    /// Releases the last lock held by `reason`.
+    pub fn unlock_with(&mut self, reason: NavLockReason) -> &mut Self {
+        self.commands.send_event(NavRequest::UnlockWith(reason));
+        self
+    }
+}
+
+/// Extension trait for navigation commands on a [`Focusable`] entity.
+pub trait UiNavEntityCommandsExt {
+    /// Sets focus on the focusable once it can receive focus. See [`UiNavCommands::focus`].
+    fn focus(&mut self) -> &mut Self;
+
+    /// Disables the focusable, which blocks focus and click events.
+    fn disable_focus(&mut self) -> &mut Self;
+
+    /// Enables the focusable again after [`UiNavEntityCommandsExt::disable_focus`].
+    fn enable_focus(&mut self) -> &mut Self;
+}
+
+impl UiNavEntityCommandsExt for EntityCommands<'_> {
+    fn focus(&mut self) -> &mut Self {
+        let entity = self.id();
+        self.commands().ui_nav().focus(entity);
+        self
+    }
+
+    fn disable_focus(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            if let Some(mut focusable) = entity.get_mut::<Focusable>() {
+                focusable.disable();
+            }
+        })
+    }
+
+    fn enable_focus(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            if let Some(mut focusable) = entity.get_mut::<Focusable>() {
+                focusable.enable();
+            }
+        })
+    }
+}
+
+/// Returns whether a focusable has been set up and laid out, so that a focus request for it is not ignored.
+///
+/// Disabled focusables are treated as ready, so that a request for one is dropped rather than applied once enabled.
+fn is_ready(focusable: &Focusable) -> bool {
+    focusable.menu.is_some()
+        && (focusable.is_visible || focusable.is_priority || focusable.is_disabled)
+}
+
+/// System that sends the pending focus request once its target focusable, or a focusable in its target menu, is ready.
+///
+/// Requests whose target is not ready after [`PENDING_FOCUS_MAX_FRAMES`] frames are dropped with a warning, as the
+/// target is most likely not in a [`NavMenu`] or will never be visible.
+pub(crate) fn apply_pending_focus(
+    mut pending: ResMut<UiNavPendingFocus>,
+    query: Query<&Focusable>,
+    menu_query: Query<(), With<NavMenu>>,
+    entity_query: Query<()>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+) {
+    let Some(entity) = pending.entity else {
+        return;
+    };
+
+    // drop requests for despawned entities
+    if !entity_query.contains(entity) {
+        pending.entity = None;
+        return;
+    }
+
+    let is_ready = match query.get(entity) {
+        Ok(focusable) => is_ready(focusable),
+        Err(_) if menu_query.contains(entity) => query.iter().any(|focusable| {
+            focusable.menu == Some(entity) && !focusable.is_mouse_only && is_ready(focusable)
+        }),
+        Err(_) => true,
+    };
+    if is_ready {
+        pending.entity = None;
+        nav_request_writer.write(NavRequest::SetFocus {
+            entity,
+            interaction_type: UiNavInteractionType::Manual,
+        });
+    } else if pending.frames >= PENDING_FOCUS_MAX_FRAMES {
+        warn!(
+            "Dropping the focus request for {entity}, which was not ready after {PENDING_FOCUS_MAX_FRAMES} frames"
+        );
+        pending.entity = None;
+    } else {
+        pending.frames += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn focus_is_applied_after_spawning() {
+        let mut app = test_app();
+        let (_, buttons) = spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        // focus the button before it has been set up
+        app.world_mut().commands().entity(buttons[1]).focus();
+        app.world_mut().flush();
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), Some(buttons[1]));
+
+        // disabling the focused button moves focus to the other one
+        app.world_mut()
+            .commands()
+            .entity(buttons[1])
+            .disable_focus();
+        app.world_mut().flush();
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), Some(buttons[0]));
+    }
+
+    #[test]
+    fn focus_is_dropped_when_the_target_never_becomes_ready() {
+        let mut app = test_app();
+        spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 1);
+        // a focusable outside of a menu can never receive focus
+        let orphan = app
+            .world_mut()
+            .spawn((Focusable::default(), focus_node(Vec2::ZERO, Vec2::ONE)))
+            .id();
+        app.world_mut().commands().entity(orphan).focus();
+        app.world_mut().flush();
+
+        for _ in 0..PENDING_FOCUS_MAX_FRAMES {
+            app.update();
+        }
+        assert_eq!(
+            app.world().resource::<UiNavPendingFocus>().entity,
+            Some(orphan)
+        );
+        app.update();
+        assert_eq!(app.world().resource::<UiNavPendingFocus>().entity, None);
+    }
+}
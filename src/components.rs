@@ -55,6 +55,43 @@ impl NavMenu {
     }
 }
 
+/// Marker component on the current [`NavMenu`], which is inserted and removed as the current menu changes.
+///
+/// Use `Without<CurrentNavMenu>` to dim inactive panels, or `Added<CurrentNavMenu>` to react to a menu being opened.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct CurrentNavMenu;
+
+/// Marker component on a [`Focusable`] while it is focused.
+///
+/// The focus marker components are inserted and removed whenever the `Focusable` state changes, so they can be used
+/// in query filters such as `With<Focused>` and `Added<NavPressed>`, or with component hooks and observers.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct Focused;
+
+/// Marker component on a [`Focusable`] while it is pressed. It is prefixed to avoid a clash with the `Pressed`
+/// picking event.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct NavPressed;
+
+/// Marker component on a [`Focusable`] while it is hovered by the mouse.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct Hovered;
+
+/// Marker component on a [`Focusable`] while it is disabled.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct NavDisabled;
+
+/// Marker component on every ancestor of a focused [`Focusable`], which can be used to highlight the rows or panels
+/// containing focus.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct FocusWithin;
+
 /// Component which marks a node as focusable.
 #[derive(Component, Default, Debug, Clone, Reflect)]
 #[reflect(Component, Default, Debug)]
@@ -154,3 +191,41 @@ impl Focusable {
         self.is_disabled = true;
     }
 }
+
+/// Component that lets a focused [`Focusable`] claim movement instead of moving focus.
+///
+/// Claimed movement is sent as a [`UiNavCapturedMovementEvent`] and the spatial navigation is skipped. Diagonal movement
+/// is only claimed if both axes are claimed.
+///
+/// [`UiNavCapturedMovementEvent`]: crate::prelude::UiNavCapturedMovementEvent
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct NavCaptureMovement {
+    /// Whether `Left` and `Right` movement is claimed
+    pub horizontal: bool,
+    /// Whether `Up` and `Down` movement is claimed
+    pub vertical: bool,
+}
+
+impl NavCaptureMovement {
+    /// Claims `Left` and `Right` movement.
+    pub const HORIZONTAL: Self = Self {
+        horizontal: true,
+        vertical: false,
+    };
+
+    /// Claims `Up` and `Down` movement.
+    pub const VERTICAL: Self = Self {
+        horizontal: false,
+        vertical: true,
+    };
+
+    /// Returns whether movement in `direction` is claimed.
+    pub fn captures(&self, direction: UiNavDirection) -> bool {
+        match direction {
+            UiNavDirection::Left | UiNavDirection::Right => self.horizontal,
+            UiNavDirection::Up | UiNavDirection::Down => self.vertical,
+            _ => self.horizontal && self.vertical,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+use crate::{input::ActionType, resources::*};
+
+/// Run condition that returns `true` if the UI navigation plugin consumed `action` this frame.
+///
+/// Systems using this condition should be scheduled after [`UiNavSet`], for example:
+/// `jump.after(UiNavSet).run_if(not(ui_nav_consumed(ActionType::Action)))`.
+pub fn ui_nav_consumed(action: ActionType) -> impl Condition<()> {
+    IntoSystem::into_system(move |consumed: Res<UiNavConsumedInput>| consumed.consumed(action))
+}
+
+/// Run condition that returns `true` if a menu currently has focus.
+pub fn ui_has_focus() -> impl Condition<()> {
+    IntoSystem::into_system(|nav_state: Res<UiNavState>| nav_state.menu.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        ecs::system::RunSystemOnce,
+        input::{keyboard::Key, ButtonState},
+    };
+
+    use super::*;
+    use crate::{components::NavMenu, test_utils::*};
+
+    fn run_condition(app: &mut App, condition: impl Condition<()>) -> bool {
+        app.world_mut().run_system_once(condition).unwrap()
+    }
+
+    #[test]
+    fn consumed_input_is_reported_and_cleared() {
+        let mut app = test_app();
+        app.insert_resource(UiNavSettings {
+            clear_consumed_input: true,
+            ..default()
+        });
+        spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        app.update();
+        app.update();
+        assert!(run_condition(&mut app, ui_has_focus()));
+
+        send_key(
+            &mut app,
+            KeyCode::ArrowDown,
+            Key::ArrowDown,
+            ButtonState::Pressed,
+        );
+        assert!(run_condition(&mut app, ui_nav_consumed(ActionType::Down)));
+        assert!(!run_condition(&mut app, ui_nav_consumed(ActionType::Up)));
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.pressed(KeyCode::ArrowDown));
+        assert!(!keys.just_pressed(KeyCode::ArrowDown));
+    }
+
+    #[test]
+    fn input_is_not_consumed_without_a_menu() {
+        let mut app = test_app();
+        app.insert_resource(UiNavSettings {
+            clear_consumed_input: true,
+            ..default()
+        });
+        app.update();
+        assert!(!run_condition(&mut app, ui_has_focus()));
+
+        send_key(
+            &mut app,
+            KeyCode::ArrowDown,
+            Key::ArrowDown,
+            ButtonState::Pressed,
+        );
+        assert!(!run_condition(&mut app, ui_nav_consumed(ActionType::Down)));
+        assert!(app.world().resource::<UiNavConsumedInput>().is_empty());
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .just_pressed(KeyCode::ArrowDown));
+    }
+}
@@ -0,0 +1,286 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition, window::PrimaryWindow};
+
+use crate::{components::*, events::*, popup::*, resources::UiNavState};
+
+/// Where a [`NavContextMenu`] is placed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub enum NavContextMenuAnchor {
+    /// Below the owner's node, aligned with its left edge
+    #[default]
+    Owner,
+    /// With its top-left corner at a point in logical pixels, such as the cursor position
+    Point(Vec2),
+}
+
+/// Component for a context menu, which is a [`NavPopup`] placed next to its owner or at the cursor.
+///
+/// Spawn one with [`NavContextMenu::bundle`] in response to a [`UiNavContextMenuEvent`], which is sent when a
+/// focusable is right-clicked or the `ContextMenu` action is pressed while it is focused. The menu is hidden until it
+/// has been laid out, then moved to its anchor. When it would extend past the right or bottom edge of the primary
+/// window, it is flipped to the other side of the anchor. Give one of its items `Focusable::prioritized()` so that it
+/// takes focus when the menu is opened.
+///
+/// Like other popups, the menu is closed on `Cancel` or when the mouse is pressed outside of it, and focus is returned
+/// to its owner. It is also closed after any focusable within it is clicked.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct NavContextMenu {
+    pub anchor: NavContextMenuAnchor,
+}
+
+impl NavContextMenu {
+    pub fn new(anchor: NavContextMenuAnchor) -> Self {
+        Self { anchor }
+    }
+
+    /// Returns the components for a context menu opened from `owner`.
+    pub fn bundle(owner: Entity, anchor: NavContextMenuAnchor) -> impl Bundle {
+        let position = match anchor {
+            NavContextMenuAnchor::Owner => Vec2::ZERO,
+            NavContextMenuAnchor::Point(point) => point,
+        };
+        (
+            Self::new(anchor),
+            NavPopup::bundle(owner, position),
+            Visibility::Hidden,
+        )
+    }
+}
+
+/// Returns the top-left position of a menu of `size` placed below and to the right of `anchor`, flipped above or to
+/// the left of it where it would not fit within `viewport`.
+pub(crate) fn context_menu_position(anchor: Rect, size: Vec2, viewport: Vec2) -> Vec2 {
+    let mut position = Vec2::new(anchor.min.x, anchor.max.y);
+    if position.x + size.x > viewport.x {
+        position.x = anchor.max.x - size.x;
+    }
+    if position.y + size.y > viewport.y {
+        position.y = anchor.min.y - size.y;
+    }
+    position.min(viewport - size).max(Vec2::ZERO)
+}
+
+/// System that sends a [`UiNavContextMenuEvent`] when a focusable is right-clicked.
+///
+/// The event is sent when the button is released, so that the press does not close the menu it opens.
+pub(crate) fn handle_context_menu_mouse(
+    query: Query<(Entity, &Focusable, &Interaction, &RelativeCursorPosition)>,
+    menu_query: Query<&NavMenu>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    nav_state: Res<UiNavState>,
+    mut context_menu_writer: EventWriter<UiNavContextMenuEvent>,
+) {
+    if nav_state.lock_scope().mouse {
+        return;
+    }
+
+    let is_current_menu_locked = nav_state
+        .menu
+        .and_then(|e| menu_query.get(e).ok())
+        .is_some_and(|nav_menu| nav_menu.is_locked);
+
+    let hovered = query.iter().find(|(_, focusable, interaction, cursor)| {
+        // ignore focusables outside the current menu when either menu is locked
+        let is_menu_locked = focusable
+            .menu
+            .and_then(|e| menu_query.get(e).ok())
+            .is_some_and(|nav_menu| nav_menu.is_locked);
+        let is_in_current_menu = focusable.menu == nav_state.menu;
+        !focusable.is_disabled
+            && **interaction != Interaction::None
+            && cursor.mouse_over()
+            && (is_in_current_menu || !(is_current_menu_locked || is_menu_locked))
+    });
+    if let Some((entity, ..)) = hovered {
+        let anchor = window_query
+            .single()
+            .ok()
+            .and_then(|window| window.cursor_position())
+            .map_or(NavContextMenuAnchor::Owner, NavContextMenuAnchor::Point);
+        context_menu_writer.write(UiNavContextMenuEvent { entity, anchor });
+    }
+}
+
+/// System that moves context menus to their anchor once they have been laid out, and shows them.
+pub(crate) fn position_context_menus(
+    mut query: Query<(
+        &NavContextMenu,
+        &NavPopup,
+        &ComputedNode,
+        &mut Node,
+        &mut Visibility,
+    )>,
+    owner_query: Query<(&ComputedNode, &GlobalTransform)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let viewport = window_query
+        .single()
+        .map_or(Vec2::INFINITY, |window| window.size());
+    for (context_menu, popup, computed_node, mut node, mut visibility) in query.iter_mut() {
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        if size.x <= f32::EPSILON || size.y <= f32::EPSILON {
+            continue;
+        }
+        let anchor = match context_menu.anchor {
+            NavContextMenuAnchor::Point(point) => Rect::from_corners(point, point),
+            NavContextMenuAnchor::Owner => match owner_query.get(popup.owner) {
+                Ok((owner_node, transform)) => node_rect(owner_node, transform),
+                Err(_) => continue,
+            },
+        };
+        let position = context_menu_position(anchor, size, viewport);
+        if node.left != Val::Px(position.x) || node.top != Val::Px(position.y) {
+            node.left = Val::Px(position.x);
+            node.top = Val::Px(position.y);
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+/// System that closes a context menu after a focusable within it is clicked.
+pub(crate) fn handle_context_menu_clicks(
+    mut commands: Commands,
+    mut events: EventReader<UiNavClickEvent>,
+    query: Query<&NavPopup, With<NavContextMenu>>,
+    parent_query: Query<&ChildOf>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+    mut closed_writer: EventWriter<UiNavPopupClosedEvent>,
+) {
+    for event in events.read() {
+        let context_menu = parent_query
+            .iter_ancestors(event.0)
+            .find_map(|e| query.get(e).ok().map(|popup| (e, popup)));
+        if let Some((entity, popup)) = context_menu {
+            close_popup(
+                &mut commands,
+                &mut nav_request_writer,
+                &mut closed_writer,
+                entity,
+                popup,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::{keyboard::Key, ButtonState};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn context_menu_flips_at_viewport_edges() {
+        let viewport = Vec2::new(800., 600.);
+        let size = Vec2::new(200., 100.);
+        let anchor = Rect::new(100., 100., 300., 130.);
+        assert_eq!(
+            context_menu_position(anchor, size, viewport),
+            Vec2::new(100., 130.)
+        );
+
+        // flips above the anchor at the bottom edge
+        let anchor = Rect::new(100., 550., 300., 580.);
+        assert_eq!(
+            context_menu_position(anchor, size, viewport),
+            Vec2::new(100., 450.)
+        );
+
+        // flips left of a point at the right edge
+        let point = Vec2::new(700., 200.);
+        assert_eq!(
+            context_menu_position(Rect::from_corners(point, point), size, viewport),
+            Vec2::new(500., 200.)
+        );
+    }
+
+    #[test]
+    fn context_menu_action_opens_menu_and_click_restores_focus() {
+        let mut app = test_app();
+        let mut button = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(NavMenu::default().prioritized())
+            .with_children(|p| {
+                button = p
+                    .spawn((
+                        Focusable::prioritized(),
+                        focus_node(Vec2::ZERO, Vec2::new(100., 20.)),
+                    ))
+                    .id();
+            });
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), Some(button));
+
+        // the context menu action requests a menu for the focused button
+        tap_key(&mut app, KeyCode::ContextMenu, Key::ContextMenu);
+        let events = read_events::<UiNavContextMenuEvent>(&app);
+        assert_eq!(
+            events,
+            vec![UiNavContextMenuEvent {
+                entity: button,
+                anchor: NavContextMenuAnchor::Owner
+            }]
+        );
+
+        // open the menu, which takes focus
+        let mut item = Entity::PLACEHOLDER;
+        let context_menu = app
+            .world_mut()
+            .spawn(NavContextMenu::bundle(button, events[0].anchor))
+            .with_children(|p| {
+                item = p
+                    .spawn((
+                        Focusable::prioritized(),
+                        focus_node(Vec2::new(0., 40.), Vec2::new(100., 20.)),
+                    ))
+                    .id();
+            })
+            .id();
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), Some(item));
+
+        // clicking an item closes the menu and focuses the button again
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        app.update();
+        assert!(app.world().get_entity(context_menu).is_err());
+        assert_eq!(focused(&mut app), Some(button));
+        assert_eq!(
+            read_events::<UiNavPopupClosedEvent>(&app),
+            vec![UiNavPopupClosedEvent {
+                popup: context_menu,
+                owner: button
+            }]
+        );
+    }
+
+    #[test]
+    fn right_click_requests_a_context_menu() {
+        let mut app = test_app();
+        let (_, buttons) = spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        app.update();
+        app.update();
+
+        // the menu is requested for the hovered focusable when the right button is released
+        set_interaction(&mut app, buttons[1], Interaction::Hovered);
+        send_mouse_button(&mut app, MouseButton::Right, ButtonState::Pressed);
+        assert!(read_events::<UiNavContextMenuEvent>(&app).is_empty());
+        send_mouse_button(&mut app, MouseButton::Right, ButtonState::Released);
+        assert_eq!(
+            read_events::<UiNavContextMenuEvent>(&app),
+            vec![UiNavContextMenuEvent {
+                entity: buttons[1],
+                anchor: NavContextMenuAnchor::Owner
+            }]
+        );
+
+        // nothing is requested when no focusable is hovered
+        clear_events::<UiNavContextMenuEvent>(&mut app);
+        set_interaction(&mut app, buttons[1], Interaction::None);
+        send_mouse_button(&mut app, MouseButton::Right, ButtonState::Pressed);
+        send_mouse_button(&mut app, MouseButton::Right, ButtonState::Released);
+        assert!(read_events::<UiNavContextMenuEvent>(&app).is_empty());
+    }
+}
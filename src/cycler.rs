@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+use crate::{components::*, events::*, types::UiNavDirection, utils::find_text_entity};
+
+/// Component for a focusable that cycles through a list of options, such as "Low / Medium / High".
+///
+/// While focused, `Left` and `Right` select the previous or next option instead of moving focus. When `wrap` is
+/// `true`, moving past the last option selects the first one and vice versa.
+///
+/// The selected option is written to the entity's `Text`, or the `Text` of its first child that has one.
+///
+/// A [`UiNavCyclerChangedEvent`] is sent whenever the selected option is changed by the user.
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(Focusable, NavCaptureMovement = NavCaptureMovement::HORIZONTAL)]
+pub struct NavCycler {
+    pub options: Vec<String>,
+    pub index: usize,
+    pub wrap: bool,
+}
+
+impl NavCycler {
+    pub fn new<S: Into<String>>(options: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            index: 0,
+            wrap: false,
+        }
+    }
+
+    /// Sets the `index` value and returns the `NavCycler`.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Sets the `wrap` value to `true` and returns the `NavCycler`.
+    pub fn wrapping(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Returns the selected option, or `None` if there are no options.
+    pub fn selected(&self) -> Option<&str> {
+        self.options.get(self.index).map(String::as_str)
+    }
+
+    /// Returns the index after moving `steps` options from the current one, respecting `wrap`.
+    fn step_index(&self, steps: isize) -> usize {
+        let len = self.options.len() as isize;
+        if len == 0 {
+            return 0;
+        }
+        let index = self.index as isize + steps;
+        if self.wrap {
+            index.rem_euclid(len) as usize
+        } else {
+            index.clamp(0, len - 1) as usize
+        }
+    }
+}
+
+/// System that changes the selected option of cyclers when they claim movement.
+pub(crate) fn handle_cycler_movement(
+    mut events: EventReader<UiNavCapturedMovementEvent>,
+    mut query: Query<&mut NavCycler>,
+    mut changed_writer: EventWriter<UiNavCyclerChangedEvent>,
+) {
+    for event in events.read() {
+        if let Ok(mut cycler) = query.get_mut(event.entity) {
+            let steps = match event.direction {
+                UiNavDirection::Left => -1,
+                UiNavDirection::Right => 1,
+                _ => continue,
+            };
+            let index = cycler.step_index(steps);
+            if index != cycler.index {
+                cycler.index = index;
+                changed_writer.write(UiNavCyclerChangedEvent {
+                    entity: event.entity,
+                    index,
+                });
+            }
+        }
+    }
+}
+
+/// System that writes the selected option of cyclers to their text.
+pub(crate) fn update_cycler_display(
+    query: Query<(Entity, &NavCycler, Option<&Children>), Changed<NavCycler>>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (entity, cycler, children) in query.iter() {
+        let target = find_text_entity(entity, children, &text_query);
+        if let Some(mut text) = target.and_then(|e| text_query.get_mut(e).ok()) {
+            let selected = cycler.selected().unwrap_or_default();
+            if text.0 != selected {
+                text.0 = selected.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::{prelude::NavMenu, test_utils::*};
+
+    #[test]
+    fn step_index_respects_wrap() {
+        let cycler = NavCycler::new(["Low", "Medium", "High"]);
+        assert_eq!(cycler.step_index(-1), 0);
+        assert_eq!(cycler.clone().with_index(2).step_index(1), 2);
+
+        let cycler = cycler.wrapping();
+        assert_eq!(cycler.step_index(-1), 2);
+        assert_eq!(cycler.with_index(2).step_index(1), 0);
+    }
+
+    #[test]
+    fn focused_cycler_claims_horizontal_movement() {
+        let mut app = test_app();
+        let mut cycler = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(NavMenu::default().prioritized())
+            .with_children(|p| {
+                cycler = p
+                    .spawn((
+                        NavCycler::new(["Low", "Medium", "High"]),
+                        Focusable::prioritized(),
+                        focus_node(Vec2::ZERO, Vec2::new(100., 20.)),
+                        Text::default(),
+                    ))
+                    .id();
+                p.spawn((
+                    Focusable::default(),
+                    focus_node(Vec2::new(200., 0.), Vec2::new(100., 20.)),
+                ));
+            });
+        app.update();
+        app.update();
+        assert_eq!(app.world().get::<Text>(cycler).unwrap().0, "Low");
+
+        tap_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+        assert_eq!(focused(&mut app), Some(cycler));
+        assert_eq!(app.world().get::<NavCycler>(cycler).unwrap().index, 1);
+        assert_eq!(app.world().get::<Text>(cycler).unwrap().0, "Medium");
+        assert_eq!(
+            read_events::<UiNavCyclerChangedEvent>(&app),
+            vec![UiNavCyclerChangedEvent {
+                entity: cycler,
+                index: 1
+            }]
+        );
+    }
+}
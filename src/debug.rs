@@ -0,0 +1,179 @@
+use bevy::{color::palettes::css, platform::collections::HashMap, prelude::*};
+
+use crate::{
+    components::*, popup::node_rect, resources::*, spatial_map::UiSpatialMap, types::UiNavDirection,
+};
+
+/// Plugin that draws the navigation state with gizmos, to find dead ends and unreachable focusables.
+///
+/// Each visible [`Focusable`] is outlined in a color for its state, each [`NavMenu`] is outlined around its focusables
+/// with the current menu highlighted, and arrows point from the focused focusable to the targets of Up, Down, Left and
+/// Right movement. Directions without a target are drawn as short red arrows.
+///
+/// Requires [`BevyUiNavPlugin`] and a `Camera2d`, whose viewport the UI is drawn in.
+///
+/// [`BevyUiNavPlugin`]: crate::prelude::BevyUiNavPlugin
+pub struct UiNavDebugPlugin;
+
+impl Plugin for UiNavDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiNavDebugSettings>().add_systems(
+            Update,
+            (
+                toggle_nav_debug,
+                draw_nav_debug.run_if(|settings: Res<UiNavDebugSettings>| settings.enabled),
+            )
+                .chain()
+                .after(UiNavSet),
+        );
+    }
+}
+
+/// Resource containing settings for the [`UiNavDebugPlugin`].
+#[derive(Resource, Debug)]
+pub struct UiNavDebugSettings {
+    /// Whether the overlay is drawn
+    pub enabled: bool,
+    /// Key that toggles the overlay, or `None` to only toggle it with `enabled`
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for UiNavDebugSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            toggle_key: Some(KeyCode::F3),
+        }
+    }
+}
+
+/// Returns the unit vector of a direction in UI space, where y points down.
+fn direction_vector(direction: UiNavDirection) -> Vec2 {
+    match direction {
+        UiNavDirection::Up => Vec2::NEG_Y,
+        UiNavDirection::Down => Vec2::Y,
+        UiNavDirection::Left => Vec2::NEG_X,
+        UiNavDirection::Right => Vec2::X,
+        UiNavDirection::UpLeft => Vec2::new(-1., -1.).normalize(),
+        UiNavDirection::UpRight => Vec2::new(1., -1.).normalize(),
+        UiNavDirection::DownLeft => Vec2::new(-1., 1.).normalize(),
+        UiNavDirection::DownRight => Vec2::new(1., 1.).normalize(),
+    }
+}
+
+/// Returns the color of a focusable's outline.
+fn focusable_color(focusable: &Focusable) -> Srgba {
+    if focusable.is_disabled {
+        css::GRAY
+    } else if focusable.is_pressed() {
+        css::RED
+    } else if focusable.active() {
+        css::YELLOW
+    } else if focusable.is_hovered() {
+        css::AQUA
+    } else {
+        css::WHITE
+    }
+}
+
+/// System that toggles the overlay when the toggle key is pressed.
+fn toggle_nav_debug(keys: Res<ButtonInput<KeyCode>>, mut settings: ResMut<UiNavDebugSettings>) {
+    if settings
+        .toggle_key
+        .is_some_and(|key| keys.just_pressed(key))
+    {
+        settings.enabled = !settings.enabled;
+    }
+}
+
+/// System that draws the focusables, menus and movement targets.
+#[allow(clippy::type_complexity)]
+fn draw_nav_debug(
+    nav_state: Res<UiNavState>,
+    menu_query: Query<(Entity, &NavMenu)>,
+    query: Query<(
+        Entity,
+        &Focusable,
+        &ComputedNode,
+        &GlobalTransform,
+        &InheritedVisibility,
+    )>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera2d>>,
+    mut gizmos: Gizmos,
+) {
+    let Some((camera, camera_transform)) = camera_query.iter().find(|(camera, _)| camera.is_active)
+    else {
+        return;
+    };
+    // converts a point in logical UI coordinates to world coordinates
+    let to_world = |point: Vec2| camera.viewport_to_world_2d(camera_transform, point).ok();
+    let draw_rect = |gizmos: &mut Gizmos, rect: Rect, color: Srgba| {
+        if let (Some(a), Some(b)) = (to_world(rect.min), to_world(rect.max)) {
+            let rect = Rect::from_corners(a, b);
+            gizmos.rect_2d(
+                Isometry2d::from_translation(rect.center()),
+                rect.size(),
+                color,
+            );
+        }
+    };
+
+    // outline the visible focusables, and collect the bounds of each menu
+    let mut rects = HashMap::<Entity, Rect>::new();
+    let mut menu_bounds = HashMap::<Entity, Rect>::new();
+    for (entity, focusable, computed_node, transform, visibility) in query.iter() {
+        let rect = node_rect(computed_node, transform);
+        if !visibility.get() || rect.is_empty() {
+            continue;
+        }
+        draw_rect(&mut gizmos, rect, focusable_color(focusable));
+        rects.insert(entity, rect);
+        if let Some(menu) = focusable.menu {
+            let bounds = menu_bounds.entry(menu).or_insert(rect);
+            *bounds = bounds.union(rect);
+        }
+    }
+    for (menu, bounds) in menu_bounds {
+        let color = if nav_state.menu == Some(menu) {
+            css::LIME
+        } else {
+            css::CORNFLOWER_BLUE
+        };
+        draw_rect(&mut gizmos, bounds.inflate(6.), color);
+    }
+
+    // draw arrows to the targets of movement from the focused focusable
+    let spatial_map = UiSpatialMap::new(&menu_query, &query, &nav_state);
+    let Some(focused) = spatial_map.focusable() else {
+        return;
+    };
+    let Some(focused_rect) = rects.get(&focused) else {
+        return;
+    };
+    for direction in UiNavDirection::CARDINAL {
+        let trace = spatial_map.trace_movement(focused, direction);
+        let target_rect = trace
+            .as_ref()
+            .and_then(|trace| trace.target())
+            .and_then(|target| rects.get(&target));
+        let (start, end, color) = match (target_rect, trace) {
+            (Some(target_rect), Some(trace)) => {
+                let color = if trace.is_wrapped() {
+                    css::ORANGE
+                } else {
+                    css::LIME
+                };
+                (focused_rect.center(), target_rect.center(), color)
+            }
+            // a dead end
+            _ => {
+                let vector = direction_vector(direction);
+                let edge = focused_rect.center() + vector * focused_rect.half_size();
+                (edge, edge + vector * 12., css::RED)
+            }
+        };
+        if let (Some(start), Some(end)) = (to_world(start), to_world(end)) {
+            gizmos.arrow_2d(start, end, color);
+        }
+    }
+}
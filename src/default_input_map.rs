@@ -31,6 +31,25 @@ pub(crate) const DEFAULT_INPUT_MAP: &[InputMapping] = &[
         keycode: KeyCode::Escape,
         action: ActionType::Cancel,
     },
+    InputMapping::KeyChord {
+        modifiers: KeyModifiers::CONTROL_SHIFT,
+        keycode: KeyCode::Tab,
+        action: ActionType::PreviousTab,
+    },
+    InputMapping::KeyChord {
+        modifiers: KeyModifiers::CONTROL,
+        keycode: KeyCode::Tab,
+        action: ActionType::NextTab,
+    },
+    InputMapping::Key {
+        keycode: KeyCode::ContextMenu,
+        action: ActionType::ContextMenu,
+    },
+    InputMapping::KeyChord {
+        modifiers: KeyModifiers::SHIFT,
+        keycode: KeyCode::F10,
+        action: ActionType::ContextMenu,
+    },
     InputMapping::GamepadButton {
         gamepad: None,
         button: GamepadButton::DPadUp,
@@ -61,8 +80,20 @@ pub(crate) const DEFAULT_INPUT_MAP: &[InputMapping] = &[
         button: GamepadButton::East,
         action: ActionType::Cancel,
     },
-    InputMapping::GamepadAxes {
+    InputMapping::GamepadButton {
+        gamepad: None,
+        button: GamepadButton::LeftTrigger,
+        action: ActionType::PreviousTab,
+    },
+    InputMapping::GamepadButton {
+        gamepad: None,
+        button: GamepadButton::RightTrigger,
+        action: ActionType::NextTab,
+    },
+    InputMapping::GamepadButton {
         gamepad: None,
-        stick: GamepadStick::Left,
+        button: GamepadButton::West,
+        action: ActionType::ContextMenu,
     },
+    InputMapping::gamepad_stick(None, GamepadStick::Left),
 ];
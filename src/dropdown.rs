@@ -0,0 +1,334 @@
+use bevy::prelude::*;
+
+use crate::{components::*, events::*, popup::*, utils::find_text_entity};
+
+/// Component for a focusable that opens a popup list of options when clicked.
+///
+/// The popup is a [`NavPopup`] positioned below the dropdown. It takes focus, starting on the selected option, and
+/// navigation is locked to it until it is closed. Clicking an option selects it and closes the popup. Pressing
+/// `Cancel` or clicking outside of it closes the popup without changing the selection. Focus returns to the dropdown
+/// when the popup closes.
+///
+/// The selected option is written to the entity's `Text`, or the `Text` of its first child that has one. The options
+/// in the popup copy the `TextFont` and `TextColor` of that text, and are padded by `option_padding` on a
+/// `popup_background`. Options have a [`NavDropdownOption`] component, so they can be styled like any other focusable.
+///
+/// A [`UiNavDropdownChangedEvent`] is sent whenever the selected option is changed by the user.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(Focusable)]
+pub struct NavDropdown {
+    pub options: Vec<String>,
+    pub selected: usize,
+    /// The background color of the popup
+    pub popup_background: Color,
+    /// The padding around each option in the popup
+    pub option_padding: Val,
+    /// The open popup entity
+    pub(crate) popup: Option<Entity>,
+}
+
+impl Default for NavDropdown {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            selected: 0,
+            popup_background: Color::srgb(0.1, 0.1, 0.1),
+            option_padding: Val::Px(5.),
+            popup: None,
+        }
+    }
+}
+
+impl NavDropdown {
+    pub fn new<S: Into<String>>(options: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            ..default()
+        }
+    }
+
+    /// Sets the `selected` value and returns the `NavDropdown`.
+    pub fn with_selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the `popup_background` value and returns the `NavDropdown`.
+    pub fn with_popup_background(mut self, popup_background: impl Into<Color>) -> Self {
+        self.popup_background = popup_background.into();
+        self
+    }
+
+    /// Sets the `option_padding` value and returns the `NavDropdown`.
+    pub fn with_option_padding(mut self, option_padding: Val) -> Self {
+        self.option_padding = option_padding;
+        self
+    }
+
+    /// Returns the selected option, or `None` if there are no options.
+    pub fn selected_option(&self) -> Option<&str> {
+        self.options.get(self.selected).map(String::as_str)
+    }
+
+    /// Returns whether the popup is open.
+    pub fn is_open(&self) -> bool {
+        self.popup.is_some()
+    }
+}
+
+/// Component for an option in the popup of a [`NavDropdown`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct NavDropdownOption {
+    /// The dropdown entity that owns this option
+    pub dropdown: Entity,
+    /// The index of this option
+    pub index: usize,
+}
+
+/// System that opens dropdowns and selects options when they are clicked.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handle_dropdown_clicks(
+    mut commands: Commands,
+    mut events: EventReader<UiNavClickEvent>,
+    mut query: Query<(
+        &mut NavDropdown,
+        &ComputedNode,
+        &GlobalTransform,
+        Option<&Children>,
+    )>,
+    option_query: Query<(&NavDropdownOption, &ChildOf)>,
+    popup_query: Query<&NavPopup>,
+    text_query: Query<(Option<&TextFont>, Option<&TextColor>), With<Text>>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+    mut closed_writer: EventWriter<UiNavPopupClosedEvent>,
+    mut changed_writer: EventWriter<UiNavDropdownChangedEvent>,
+) {
+    for event in events.read() {
+        // select an option
+        if let Ok((option, child_of)) = option_query.get(event.0) {
+            if let Ok((mut dropdown, ..)) = query.get_mut(option.dropdown) {
+                if dropdown.selected != option.index {
+                    dropdown.selected = option.index;
+                    changed_writer.write(UiNavDropdownChangedEvent {
+                        entity: option.dropdown,
+                        index: option.index,
+                    });
+                }
+            }
+            if let Ok(popup) = popup_query.get(child_of.parent()) {
+                close_popup(
+                    &mut commands,
+                    &mut nav_request_writer,
+                    &mut closed_writer,
+                    child_of.parent(),
+                    popup,
+                );
+            }
+            continue;
+        }
+
+        // open the popup
+        let Ok((mut dropdown, node, transform, children)) = query.get_mut(event.0) else {
+            continue;
+        };
+        if dropdown.is_open() {
+            continue;
+        }
+        let rect = node_rect(node, transform);
+        let (font, color) = find_text_entity(event.0, children, &text_query)
+            .and_then(|e| text_query.get(e).ok())
+            .map(|(font, color)| (font.cloned(), color.cloned()))
+            .unwrap_or_default();
+        let popup = commands
+            .spawn((
+                NavPopup::bundle(event.0, Vec2::new(rect.min.x, rect.max.y)),
+                BackgroundColor(dropdown.popup_background),
+            ))
+            .with_children(|p| {
+                for (index, option) in dropdown.options.iter().enumerate() {
+                    p.spawn((
+                        NavDropdownOption {
+                            dropdown: event.0,
+                            index,
+                        },
+                        Focusable::default().with_priority(index == dropdown.selected),
+                        Node {
+                            min_width: Val::Px(rect.width()),
+                            padding: UiRect::all(dropdown.option_padding),
+                            ..default()
+                        },
+                    ))
+                    .with_children(|p| {
+                        p.spawn((
+                            Text::new(option.clone()),
+                            font.clone().unwrap_or_default(),
+                            color.unwrap_or_default(),
+                        ));
+                    });
+                }
+            })
+            .id();
+        dropdown.popup = Some(popup);
+    }
+}
+
+/// System that clears the open popup of dropdowns when it is closed.
+pub(crate) fn handle_dropdown_popup_closed(
+    mut events: EventReader<UiNavPopupClosedEvent>,
+    mut query: Query<&mut NavDropdown>,
+) {
+    for event in events.read() {
+        if let Ok(mut dropdown) = query.get_mut(event.owner) {
+            if dropdown.popup == Some(event.popup) {
+                dropdown.popup = None;
+            }
+        }
+    }
+}
+
+/// System that writes the selected option of dropdowns to their text.
+pub(crate) fn update_dropdown_display(
+    query: Query<(Entity, &NavDropdown, Option<&Children>), Changed<NavDropdown>>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (entity, dropdown, children) in query.iter() {
+        let target = find_text_entity(entity, children, &text_query);
+        if let Some(mut text) = target.and_then(|e| text_query.get_mut(e).ok()) {
+            let selected = dropdown.selected_option().unwrap_or_default();
+            if text.0 != selected {
+                text.0 = selected.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::{prelude::NavMenu, test_utils::*};
+
+    fn option_entity(app: &mut App, index: usize) -> Entity {
+        app.world_mut()
+            .query::<(Entity, &NavDropdownOption)>()
+            .iter(app.world())
+            .find(|(_, option)| option.index == index)
+            .map(|(entity, _)| entity)
+            .unwrap()
+    }
+
+    /// Gives the popup options a size and position, as the UI layout systems would.
+    fn layout_options(app: &mut App) {
+        let options = app
+            .world_mut()
+            .query::<(Entity, &NavDropdownOption)>()
+            .iter(app.world())
+            .map(|(entity, option)| (entity, option.index))
+            .collect::<Vec<_>>();
+        for (entity, index) in options {
+            app.world_mut().entity_mut(entity).insert(focus_node(
+                Vec2::new(0., 20. + 20. * index as f32),
+                Vec2::new(100., 20.),
+            ));
+        }
+    }
+
+    #[test]
+    fn dropdown_selects_option_and_restores_focus() {
+        let mut app = test_app();
+        let mut dropdown = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(NavMenu::default().prioritized())
+            .with_children(|p| {
+                dropdown = p
+                    .spawn((
+                        NavDropdown::new(["Low", "Medium", "High"])
+                            .with_selected(1)
+                            .with_popup_background(Color::BLACK)
+                            .with_option_padding(Val::Px(8.)),
+                        Focusable::prioritized(),
+                        focus_node(Vec2::ZERO, Vec2::new(100., 20.)),
+                        Text::default(),
+                    ))
+                    .id();
+            });
+        app.update();
+        app.update();
+        assert_eq!(app.world().get::<Text>(dropdown).unwrap().0, "Medium");
+
+        // open the popup, which focuses the selected option
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        layout_options(&mut app);
+        app.update();
+        let popup = app.world().get::<NavDropdown>(dropdown).unwrap().popup;
+        assert!(popup.is_some());
+        assert_eq!(
+            app.world()
+                .get::<BackgroundColor>(popup.unwrap())
+                .unwrap()
+                .0,
+            Color::BLACK
+        );
+        let option = option_entity(&mut app, 1);
+        assert_eq!(
+            app.world().get::<Node>(option).unwrap().padding,
+            UiRect::all(Val::Px(8.))
+        );
+        assert_eq!(focused(&mut app), Some(option));
+
+        // select the next option
+        clear_events::<UiNavClickEvent>(&mut app);
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        app.update();
+        let nav_dropdown = app.world().get::<NavDropdown>(dropdown).unwrap();
+        assert!(!nav_dropdown.is_open());
+        assert_eq!(nav_dropdown.selected, 2);
+        assert_eq!(app.world().get::<Text>(dropdown).unwrap().0, "High");
+        assert_eq!(focused(&mut app), Some(dropdown));
+        assert_eq!(
+            read_events::<UiNavDropdownChangedEvent>(&app),
+            vec![UiNavDropdownChangedEvent {
+                entity: dropdown,
+                index: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn cancel_closes_dropdown_without_selecting() {
+        let mut app = test_app();
+        let mut dropdown = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(NavMenu::default().prioritized())
+            .with_children(|p| {
+                dropdown = p
+                    .spawn((
+                        NavDropdown::new(["Low", "Medium", "High"]),
+                        Focusable::prioritized(),
+                        focus_node(Vec2::ZERO, Vec2::new(100., 20.)),
+                    ))
+                    .id();
+            });
+        app.update();
+        app.update();
+
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        layout_options(&mut app);
+        app.update();
+        assert!(app.world().get::<NavDropdown>(dropdown).unwrap().is_open());
+
+        clear_events::<UiNavClickEvent>(&mut app);
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        tap_key(&mut app, KeyCode::Escape, Key::Escape);
+        app.update();
+        let nav_dropdown = app.world().get::<NavDropdown>(dropdown).unwrap();
+        assert!(!nav_dropdown.is_open());
+        assert_eq!(nav_dropdown.selected, 0);
+        assert_eq!(focused(&mut app), Some(dropdown));
+        assert!(read_events::<UiNavDropdownChangedEvent>(&app).is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::types::*;
+use crate::{context_menu::NavContextMenuAnchor, types::*};
 
 /// Event sent when a new focusable is focused.
 ///
@@ -10,23 +10,200 @@ use crate::types::*;
 pub struct UiNavFocusChangedEvent {
     pub entity: Entity,
     pub interaction_type: UiNavInteractionType,
+    /// The focusable that had focus before, if any. Mouse-only focusables only replace other mouse-only focusables.
+    pub previous: Option<Entity>,
+    /// The menu that was current before focus changed, which differs from the menu of `entity` when focus moved to
+    /// another menu
+    pub previous_menu: Option<Entity>,
+}
+
+/// Event sent when a focusable loses focus, before the [`UiNavFocusChangedEvent`] of the focusable that replaced it.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct UiNavFocusLostEvent {
+    pub entity: Entity,
+    /// The type of interaction that moved focus away from the focusable
+    pub interaction_type: UiNavInteractionType,
+}
+
+/// Event sent when the current [`NavMenu`] changes, including when it is despawned.
+///
+/// [`NavMenu`]: crate::prelude::NavMenu
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavMenuChangedEvent {
+    pub previous: Option<Entity>,
+    pub current: Option<Entity>,
+    pub interaction_type: UiNavInteractionType,
 }
 
 /// Event emitted when the "Cancel" key is pressed. The entity is the menu.
 ///
 /// This event is emitted by this plugin and should be handled by the user if they wish to handle cancel events in a
 /// menu.
-#[derive(Event, Debug, Reflect, PartialEq, Hash)]
+#[derive(Event, Debug, Clone, Copy, Reflect, PartialEq, Eq, Hash)]
 #[reflect(Debug, PartialEq, Hash)]
 pub struct UiNavCancelEvent(pub Entity);
 
 /// Event emitted when a focusable is clicked.
 ///
 /// This event is sent by this plugin and should be handled by the user.
-#[derive(Event, Debug, PartialEq, Reflect, Hash)]
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Reflect, Hash)]
 #[reflect(Debug, PartialEq, Hash)]
 pub struct UiNavClickEvent(pub Entity);
 
+/// Event emitted when movement is claimed by the focused entity via a [`NavCaptureMovement`] component, instead of
+/// moving focus.
+///
+/// [`NavCaptureMovement`]: crate::prelude::NavCaptureMovement
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavCapturedMovementEvent {
+    pub entity: Entity,
+    pub direction: UiNavDirection,
+}
+
+/// Event emitted when the value of a [`NavSlider`] is changed by the user.
+///
+/// [`NavSlider`]: crate::prelude::NavSlider
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct UiNavSliderChangedEvent {
+    pub entity: Entity,
+    pub value: f32,
+}
+
+/// Event emitted when the selected option of a [`NavCycler`] is changed by the user.
+///
+/// [`NavCycler`]: crate::prelude::NavCycler
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavCyclerChangedEvent {
+    pub entity: Entity,
+    pub index: usize,
+}
+
+/// Event emitted when the selected option of a [`NavDropdown`] is changed by the user.
+///
+/// [`NavDropdown`]: crate::prelude::NavDropdown
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavDropdownChangedEvent {
+    pub entity: Entity,
+    pub index: usize,
+}
+
+/// Event emitted when a context menu is requested for a focusable, either by right-clicking it or by pressing the
+/// `ContextMenu` action while it is focused.
+///
+/// Handle this event to spawn a [`NavContextMenu`] for the entity, using the `anchor` to place it.
+///
+/// [`NavContextMenu`]: crate::prelude::NavContextMenu
+#[derive(Event, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct UiNavContextMenuEvent {
+    pub entity: Entity,
+    pub anchor: NavContextMenuAnchor,
+}
+
+/// Event emitted when a [`NavPopup`] is closed.
+///
+/// [`NavPopup`]: crate::prelude::NavPopup
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavPopupClosedEvent {
+    pub popup: Entity,
+    pub owner: Entity,
+}
+
+/// Event emitted when the selected tab of a [`NavTabs`] changes.
+///
+/// [`NavTabs`]: crate::prelude::NavTabs
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavTabChangedEvent {
+    /// The `NavTabs` entity
+    pub tabs: Entity,
+    /// The selected `NavTab` entity
+    pub tab: Entity,
+    /// The index of the selected tab
+    pub index: usize,
+}
+
+/// Event emitted when a row of a [`NavVirtualList`] is spawned or bound to a different item.
+///
+/// [`NavVirtualList`]: crate::prelude::NavVirtualList
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavVirtualRowBoundEvent {
+    /// The `NavVirtualList` entity
+    pub list: Entity,
+    /// The `NavVirtualRow` entity
+    pub row: Entity,
+    /// The index of the item now bound to the row, or `None` if the row is hidden
+    pub index: Option<usize>,
+}
+
+/// Event emitted when a [`NavToggle`] is clicked.
+///
+/// [`NavToggle`]: crate::prelude::NavToggle
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavToggleChangedEvent {
+    pub entity: Entity,
+    pub checked: bool,
+}
+
+/// Event emitted when the user selects a different [`NavRadioButton`] in a [`NavRadioGroup`].
+///
+/// [`NavRadioButton`]: crate::prelude::NavRadioButton
+/// [`NavRadioGroup`]: crate::prelude::NavRadioGroup
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavRadioGroupChangedEvent {
+    pub group: Entity,
+    pub selected: Entity,
+}
+
+/// Event emitted when a gamepad claims ownership of the UI via [`UiNavGamepadOwnership::start_claim`].
+///
+/// [`UiNavGamepadOwnership::start_claim`]: crate::prelude::UiNavGamepadOwnership::start_claim
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavGamepadClaimedEvent(pub Entity);
+
+/// Event emitted when a gamepad that owns the UI is disconnected.
+///
+/// A user can use these events to pause the game and show a reconnect prompt.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavGamepadDisconnectedEvent(pub Entity);
+
+/// Event emitted when a gamepad that owns the UI is reconnected after being disconnected.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavGamepadReconnectedEvent(pub Entity);
+
+/// Event emitted when the value of a [`NavTextInput`] changes while it is being edited.
+///
+/// [`NavTextInput`]: crate::prelude::NavTextInput
+#[derive(Event, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavTextInputChangedEvent {
+    pub entity: Entity,
+    pub value: String,
+}
+
+/// Event emitted when a [`NavTextInput`] is committed by pressing `Enter`.
+///
+/// [`NavTextInput`]: crate::prelude::NavTextInput
+#[derive(Event, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct UiNavTextInputSubmitEvent {
+    pub entity: Entity,
+    pub value: String,
+}
+
 /// Event used internally to trigger a UI navigation request.
 ///
 /// These events are emitted in response to keyboard or gamepad button input.
@@ -49,7 +226,22 @@ pub enum NavRequest {
     /// Refresh focus state if menus have changed
     Refresh,
     /// Lock the nav request systems. Event handling will be blocked while locked. No effect if already locked.
+    ///
+    /// This is a lock held by [`NavLockReason::Default`], so it does not release locks taken with `LockWith`.
     Lock,
     /// Unlock the nav request systems and enable event handling again. No effect if already unlocked.
     Unlock,
+    /// Add a lock held by `reason`, blocking the input in `scope`. Navigation stays locked until every lock is released.
+    LockWith {
+        reason: NavLockReason,
+        scope: NavLockScope,
+    },
+    /// Release the last lock held by a [`NavLockReason`].
+    UnlockWith(NavLockReason),
+    /// Select the next tab of the current `NavTabs`
+    NextTab,
+    /// Select the previous tab of the current `NavTabs`
+    PreviousTab,
+    /// Request a context menu for the focused `Focusable`
+    ContextMenu,
 }
@@ -0,0 +1,251 @@
+use bevy::prelude::*;
+
+use crate::{components::*, events::*, popup::node_rect, types::UiNavInteractionType};
+
+/// Component for a focus cursor, which is a UI node that moves to and resizes around the focused [`Focusable`].
+///
+/// Spawn one as a root node with an absolutely positioned `Node`, because its position is set in logical pixels with
+/// the `left`, `top`, `width` and `height` of the node. Style it as a frame, arrow or glow, and give it a
+/// `GlobalZIndex` to draw it above or below the focusables. The cursor is hidden while there is no focusable to follow.
+///
+/// Focusables are followed in any menu, or only in `menu` when it is set. When focus changes, the cursor eases from
+/// its current position to the new focusable over `duration` seconds of real time.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(NavFocusCursorState, Node, Visibility)]
+pub struct NavFocusCursor {
+    /// The menu whose focusables are followed, or `None` to follow focus in any menu
+    pub menu: Option<Entity>,
+    /// The duration in seconds of the movement to a new focusable. The cursor jumps to it if zero.
+    pub duration: f32,
+    pub ease: EaseFunction,
+    /// Offset of the cursor from the focusable, in logical pixels
+    pub offset: Vec2,
+    /// Amount the cursor extends past each edge of the focusable, in logical pixels. Negative values shrink it.
+    pub padding: Vec2,
+    /// Whether the cursor is hidden while focus is being changed by the mouse
+    pub hide_on_mouse: bool,
+}
+
+impl Default for NavFocusCursor {
+    fn default() -> Self {
+        Self {
+            menu: None,
+            duration: 0.15,
+            ease: EaseFunction::QuadraticOut,
+            offset: Vec2::ZERO,
+            padding: Vec2::ZERO,
+            hide_on_mouse: false,
+        }
+    }
+}
+
+impl NavFocusCursor {
+    /// Sets the `menu` value and returns the `NavFocusCursor`.
+    pub fn with_menu(mut self, menu: Entity) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Sets the `duration` and `ease` values and returns the `NavFocusCursor`.
+    pub fn with_transition(mut self, duration: f32, ease: EaseFunction) -> Self {
+        self.duration = duration;
+        self.ease = ease;
+        self
+    }
+
+    /// Sets the `offset` value and returns the `NavFocusCursor`.
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the `padding` value and returns the `NavFocusCursor`.
+    pub fn with_padding(mut self, padding: Vec2) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the `hide_on_mouse` value to `true` and returns the `NavFocusCursor`.
+    pub fn hidden_on_mouse(mut self) -> Self {
+        self.hide_on_mouse = true;
+        self
+    }
+
+    /// Returns the rect the cursor should cover for a focusable covering `rect`.
+    fn target_rect(&self, rect: Rect) -> Rect {
+        Rect {
+            min: rect.min - self.padding + self.offset,
+            max: rect.max + self.padding + self.offset,
+        }
+    }
+}
+
+/// Component tracking the movement of a [`NavFocusCursor`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub(crate) struct NavFocusCursorState {
+    from: Rect,
+    to: Rect,
+    elapsed: f32,
+    is_visible: bool,
+    is_mouse: bool,
+}
+
+impl NavFocusCursorState {
+    /// Returns the rect at the current point of the movement.
+    fn current(&self, cursor: &NavFocusCursor) -> Rect {
+        if self.elapsed >= cursor.duration {
+            return self.to;
+        }
+        let t = cursor.ease.sample_clamped(self.elapsed / cursor.duration);
+        Rect {
+            min: self.from.min.lerp(self.to.min, t),
+            max: self.from.max.lerp(self.to.max, t),
+        }
+    }
+}
+
+/// System that moves focus cursors toward the focused focusable, and hides them while there is none.
+pub(crate) fn update_focus_cursors(
+    mut focus_events: EventReader<UiNavFocusChangedEvent>,
+    time: Res<Time<Real>>,
+    focusable_query: Query<(&Focusable, &ComputedNode, &GlobalTransform)>,
+    mut query: Query<(
+        &NavFocusCursor,
+        &mut NavFocusCursorState,
+        &mut Node,
+        &mut Visibility,
+    )>,
+) {
+    // whether focus was last changed by the mouse
+    let is_mouse = focus_events
+        .read()
+        .filter(|event| {
+            focusable_query
+                .get(event.entity)
+                .is_ok_and(|(focusable, ..)| !focusable.is_mouse_only)
+        })
+        .last()
+        .map(|event| event.interaction_type == UiNavInteractionType::Mouse);
+
+    for (cursor, mut state, mut node, mut visibility) in query.iter_mut() {
+        if let Some(is_mouse) = is_mouse {
+            state.is_mouse = is_mouse;
+        }
+
+        let target = focusable_query
+            .iter()
+            .find(|(focusable, ..)| {
+                focusable.active()
+                    && focusable.is_visible
+                    && !focusable.is_mouse_only
+                    && (cursor.menu.is_none() || focusable.menu == cursor.menu)
+            })
+            .map(|(_, computed_node, transform)| {
+                cursor.target_rect(node_rect(computed_node, transform))
+            });
+
+        let Some(target) = target.filter(|_| !(cursor.hide_on_mouse && state.is_mouse)) else {
+            state.is_visible = false;
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        if !state.is_visible {
+            // jump to the focusable when shown
+            state.from = target;
+            state.to = target;
+            state.elapsed = cursor.duration;
+            state.is_visible = true;
+        } else if state.to != target {
+            state.from = state.current(cursor);
+            state.to = target;
+            state.elapsed = 0.;
+        } else if state.elapsed < cursor.duration {
+            state.elapsed += time.delta_secs();
+        }
+
+        let rect = state.current(cursor);
+        let (left, top) = (Val::Px(rect.min.x), Val::Px(rect.min.y));
+        let (width, height) = (Val::Px(rect.width()), Val::Px(rect.height()));
+        if node.left != left || node.top != top || node.width != width || node.height != height {
+            node.left = left;
+            node.top = top;
+            node.width = width;
+            node.height = height;
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    fn spawn_menu(app: &mut App) {
+        spawn_column(
+            app,
+            NavMenu::default().prioritized(),
+            Vec2::new(50., 10.),
+            2,
+        );
+    }
+
+    fn cursor_rect(app: &App, cursor: Entity) -> Option<Rect> {
+        let world = app.world();
+        if world.get::<Visibility>(cursor) == Some(&Visibility::Hidden) {
+            return None;
+        }
+        let node = world.get::<Node>(cursor).unwrap();
+        match (node.left, node.top, node.width, node.height) {
+            (Val::Px(left), Val::Px(top), Val::Px(width), Val::Px(height)) => {
+                Some(Rect::new(left, top, left + width, top + height))
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn cursor_follows_focus() {
+        let mut app = test_app();
+        spawn_menu(&mut app);
+        let cursor = app
+            .world_mut()
+            .spawn(
+                NavFocusCursor::default()
+                    .with_transition(0., EaseFunction::Linear)
+                    .with_padding(Vec2::splat(2.)),
+            )
+            .id();
+        app.update();
+        app.update();
+        assert_eq!(
+            cursor_rect(&app, cursor),
+            Some(Rect::new(-2., -2., 102., 22.))
+        );
+
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(
+            cursor_rect(&app, cursor),
+            Some(Rect::new(-2., 48., 102., 72.))
+        );
+    }
+
+    #[test]
+    fn cursor_is_hidden_outside_its_menu() {
+        let mut app = test_app();
+        spawn_menu(&mut app);
+        let other_menu = app.world_mut().spawn(NavMenu::default()).id();
+        let cursor = app
+            .world_mut()
+            .spawn(NavFocusCursor::default().with_menu(other_menu))
+            .id();
+        app.update();
+        app.update();
+        assert_eq!(cursor_rect(&app, cursor), None);
+    }
+}
@@ -5,21 +5,25 @@ use crate::{
     utils::{distance_between_aabbs, overlap_between_aabbs},
 };
 
-/// Type used internally to describe the distance and direction to a potential navigation target.
+/// Type describing the distance and direction from the focused node to a potential navigation target.
 #[derive(Debug, Clone)]
-pub(crate) struct FocusTarget {
+pub struct FocusTarget {
     pub entity: Entity,
     pub position: Vec2,
     pub distance: FocusNodeDistance,
+    /// Whether the target overlaps the focused node along the axis perpendicular to the movement
     pub is_prefer: bool,
+    /// Whether the target lies in the direction of the movement
     pub is_in_direction: bool,
+    /// Whether the target lies along the axis of the movement, in either direction
     pub is_in_axis: bool,
+    /// Overlap of the target with the focused node along the axis perpendicular to the movement
     pub overlap: f32,
 }
 
-/// Type used internally to define a focus node's position and size.
+/// Type defining a focus node's position and size.
 #[derive(Debug, Clone)]
-pub(crate) struct FocusNode {
+pub struct FocusNode {
     pub menu: Option<Entity>,
     pub size: Vec2,
     pub position: Vec2,
@@ -56,10 +60,9 @@ impl FocusNode {
     }
 }
 
-/// Type used internally to describe the direction and distance between two nodes, and whether they overlap along any
-/// axes.
+/// Type describing the direction and distance between two nodes, and whether they overlap along any axes.
 #[derive(Debug, Clone)]
-pub(crate) struct FocusNodeDistance {
+pub struct FocusNodeDistance {
     pub is_left: bool,
     pub is_right: bool,
     pub is_up: bool,
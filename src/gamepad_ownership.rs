@@ -0,0 +1,286 @@
+use bevy::prelude::*;
+
+use crate::events::*;
+
+/// Resource naming the gamepads that own the UI.
+///
+/// When no owners are set, `InputMapping::GamepadButton { gamepad: None, .. }` and
+/// `InputMapping::GamepadAxes { gamepad: None, .. }` accept input from any gamepad. Once an owner is set, they only
+/// accept input from the owning gamepads. Mappings that name a specific gamepad are not affected.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct UiNavGamepadOwnership {
+    /// The owning gamepads
+    pub(crate) owners: Vec<Entity>,
+    /// Owning gamepads that are currently disconnected
+    pub(crate) disconnected: Vec<Entity>,
+    /// Whether the next gamepad to press a button should be claimed as an owner
+    pub(crate) is_claiming: bool,
+    /// Buttons that were held when their gamepad was claimed, which are ignored until released so that the claiming
+    /// press is not also handled as a UI action
+    #[reflect(ignore)]
+    pub(crate) suppressed: Vec<(Entity, GamepadButton)>,
+}
+
+impl UiNavGamepadOwnership {
+    /// Returns the owning gamepads.
+    pub fn owners(&self) -> &[Entity] {
+        &self.owners
+    }
+
+    /// Returns whether any gamepad owns the UI.
+    pub fn has_owner(&self) -> bool {
+        !self.owners.is_empty()
+    }
+
+    /// Returns whether `gamepad` owns the UI.
+    pub fn is_owner(&self, gamepad: Entity) -> bool {
+        self.owners.contains(&gamepad)
+    }
+
+    /// Returns whether input from `gamepad` is accepted by mappings that do not name a specific gamepad.
+    pub fn accepts(&self, gamepad: Entity) -> bool {
+        self.owners.is_empty() || self.is_owner(gamepad)
+    }
+
+    /// Replaces all owners with `gamepad`.
+    pub fn set_owner(&mut self, gamepad: Entity) {
+        self.clear();
+        self.owners.push(gamepad);
+    }
+
+    /// Adds `gamepad` as an owner. No effect if it is already an owner.
+    pub fn add_owner(&mut self, gamepad: Entity) {
+        if !self.is_owner(gamepad) {
+            self.owners.push(gamepad);
+        }
+    }
+
+    /// Removes `gamepad` from the owners.
+    pub fn remove_owner(&mut self, gamepad: Entity) {
+        self.owners.retain(|e| *e != gamepad);
+        self.disconnected.retain(|e| *e != gamepad);
+    }
+
+    /// Removes all owners, accepting input from any gamepad again.
+    pub fn clear(&mut self) {
+        self.owners.clear();
+        self.disconnected.clear();
+    }
+
+    /// Returns the owning gamepads that are currently disconnected.
+    pub fn disconnected(&self) -> &[Entity] {
+        &self.disconnected
+    }
+
+    /// Returns whether any owning gamepad is currently disconnected.
+    pub fn is_any_disconnected(&self) -> bool {
+        !self.disconnected.is_empty()
+    }
+
+    /// Starts a "press any button to claim" flow. The next gamepad to press a button will be added as an owner and a
+    /// [`UiNavGamepadClaimedEvent`] will be sent.
+    pub fn start_claim(&mut self) {
+        self.is_claiming = true;
+    }
+
+    /// Cancels a claim started with [`Self::start_claim`].
+    pub fn cancel_claim(&mut self) {
+        self.is_claiming = false;
+    }
+
+    /// Returns whether we are waiting for a gamepad to claim ownership.
+    pub fn is_claiming(&self) -> bool {
+        self.is_claiming
+    }
+
+    /// Returns whether `button` on `gamepad` is ignored because it was pressed to claim the gamepad.
+    pub(crate) fn is_suppressed(&self, gamepad: Entity, button: GamepadButton) -> bool {
+        self.suppressed.contains(&(gamepad, button))
+    }
+}
+
+/// System that assigns ownership to the first gamepad to press a button while claiming.
+///
+/// The buttons held by the claiming gamepad are ignored by the input manager until they are released.
+pub(crate) fn handle_gamepad_claim(
+    gamepads: Query<(Entity, &Gamepad)>,
+    mut ownership: ResMut<UiNavGamepadOwnership>,
+    mut claimed_writer: EventWriter<UiNavGamepadClaimedEvent>,
+) {
+    if !ownership.suppressed.is_empty() {
+        ownership.suppressed.retain(|(entity, button)| {
+            gamepads
+                .get(*entity)
+                .is_ok_and(|(_, gamepad)| gamepad.pressed(*button))
+        });
+    }
+
+    if !ownership.is_claiming {
+        return;
+    }
+
+    if let Some((entity, gamepad)) = gamepads
+        .iter()
+        .filter(|(e, _)| !ownership.is_owner(*e))
+        .find(|(_, gamepad)| gamepad.get_just_pressed().next().is_some())
+    {
+        ownership.is_claiming = false;
+        ownership.owners.push(entity);
+        let pressed = gamepad.get_pressed().map(|button| (entity, *button));
+        ownership.suppressed.extend(pressed);
+        claimed_writer.write(UiNavGamepadClaimedEvent(entity));
+    }
+}
+
+/// System that sends events when an owning gamepad disconnects or reconnects.
+///
+/// Bevy removes the `Gamepad` component when a gamepad disconnects and inserts it on the same entity when it
+/// reconnects, so ownership is preserved across reconnections.
+pub(crate) fn handle_gamepad_connections(
+    mut removed: RemovedComponents<Gamepad>,
+    added: Query<Entity, Added<Gamepad>>,
+    mut ownership: ResMut<UiNavGamepadOwnership>,
+    mut disconnected_writer: EventWriter<UiNavGamepadDisconnectedEvent>,
+    mut reconnected_writer: EventWriter<UiNavGamepadReconnectedEvent>,
+) {
+    for entity in removed.read() {
+        if ownership.is_owner(entity) && !ownership.disconnected.contains(&entity) {
+            ownership.disconnected.push(entity);
+            disconnected_writer.write(UiNavGamepadDisconnectedEvent(entity));
+        }
+    }
+
+    for entity in added.iter() {
+        if ownership.disconnected.contains(&entity) {
+            ownership.disconnected.retain(|e| *e != entity);
+            reconnected_writer.write(UiNavGamepadReconnectedEvent(entity));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::{components::NavMenu, input::*, test_utils::*};
+
+    #[test]
+    fn claim_assigns_first_gamepad_to_press() {
+        let mut app = test_app();
+        let pad_a = app.world_mut().spawn(Gamepad::default()).id();
+        let pad_b = app.world_mut().spawn(Gamepad::default()).id();
+
+        // pressing before claiming does nothing
+        tap_gamepad_button(&mut app, pad_a, GamepadButton::Start);
+        assert!(!app.world().resource::<UiNavGamepadOwnership>().has_owner());
+
+        app.world_mut()
+            .resource_mut::<UiNavGamepadOwnership>()
+            .start_claim();
+        send_gamepad_button(&mut app, pad_b, GamepadButton::South, 1.);
+
+        let ownership = app.world().resource::<UiNavGamepadOwnership>();
+        assert_eq!(ownership.owners(), &[pad_b]);
+        assert!(!ownership.is_claiming());
+        assert_eq!(
+            read_events::<UiNavGamepadClaimedEvent>(&app),
+            vec![UiNavGamepadClaimedEvent(pad_b)]
+        );
+    }
+
+    #[test]
+    fn owner_disconnect_and_reconnect_send_events() {
+        let mut app = test_app();
+        let owner = app.world_mut().spawn(Gamepad::default()).id();
+        let other = app.world_mut().spawn(Gamepad::default()).id();
+        app.world_mut()
+            .resource_mut::<UiNavGamepadOwnership>()
+            .set_owner(owner);
+        app.update();
+
+        app.world_mut().entity_mut(other).remove::<Gamepad>();
+        app.world_mut().entity_mut(owner).remove::<Gamepad>();
+        app.update();
+        assert_eq!(
+            read_events::<UiNavGamepadDisconnectedEvent>(&app),
+            vec![UiNavGamepadDisconnectedEvent(owner)]
+        );
+        assert!(app
+            .world()
+            .resource::<UiNavGamepadOwnership>()
+            .is_any_disconnected());
+
+        app.world_mut().entity_mut(owner).insert(Gamepad::default());
+        app.world_mut().entity_mut(other).insert(Gamepad::default());
+        app.update();
+        assert_eq!(
+            read_events::<UiNavGamepadReconnectedEvent>(&app),
+            vec![UiNavGamepadReconnectedEvent(owner)]
+        );
+        let ownership = app.world().resource::<UiNavGamepadOwnership>();
+        assert_eq!(ownership.owners(), &[owner]);
+        assert!(!ownership.is_any_disconnected());
+    }
+
+    #[test]
+    fn input_manager_ignores_gamepads_without_ownership() {
+        let mut world = World::new();
+        let owner = world.spawn(Gamepad::default()).id();
+        let other = world.spawn(Gamepad::default()).id();
+        world
+            .get_mut::<Gamepad>(other)
+            .unwrap()
+            .digital_mut()
+            .press(GamepadButton::South);
+        world.init_resource::<UiNavInputManager>();
+        world.init_resource::<UiNavGamepadOwnership>();
+
+        let update = |world: &mut World| {
+            world
+                .run_system_once(
+                    |mut input: ResMut<UiNavInputManager>,
+                     gamepads: Query<(Entity, &Gamepad)>,
+                     ownership: Res<UiNavGamepadOwnership>| {
+                        update_input_manager(&mut input, &gamepads, &ownership);
+                        input.pressed(ActionType::Action)
+                    },
+                )
+                .unwrap()
+        };
+
+        // any gamepad is accepted without an owner
+        assert!(update(&mut world));
+
+        world
+            .resource_mut::<UiNavGamepadOwnership>()
+            .set_owner(owner);
+        assert!(!update(&mut world));
+    }
+
+    #[test]
+    fn claiming_press_is_not_a_ui_action() {
+        let mut app = test_app();
+        spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        let pad = app.world_mut().spawn(Gamepad::default()).id();
+        app.update();
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<UiNavGamepadOwnership>()
+            .start_claim();
+        send_gamepad_button(&mut app, pad, GamepadButton::South, 1.);
+        assert_eq!(
+            app.world().resource::<UiNavGamepadOwnership>().owners(),
+            &[pad]
+        );
+        send_gamepad_button(&mut app, pad, GamepadButton::South, 0.);
+        app.update();
+        assert!(read_events::<UiNavClickEvent>(&app).is_empty());
+
+        // the next press is handled
+        tap_gamepad_button(&mut app, pad, GamepadButton::South);
+        assert_eq!(read_events::<UiNavClickEvent>(&app).len(), 1);
+    }
+}
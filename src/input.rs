@@ -1,8 +1,8 @@
-use bevy::{platform::collections::HashMap, prelude::*};
+use bevy::{math::FloatOrd, platform::collections::HashMap, prelude::*};
 
 use crate::{
     default_input_map::DEFAULT_INPUT_MAP,
-    prelude::{PressType, UiNavDirection},
+    prelude::{PressType, UiNavDirection, UiNavGamepadOwnership},
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
@@ -14,6 +14,12 @@ pub enum ActionType {
     Right,
     Action,
     Cancel,
+    /// Select the next tab of a [`NavTabs`](crate::prelude::NavTabs), wherever focus is
+    NextTab,
+    /// Select the previous tab of a [`NavTabs`](crate::prelude::NavTabs), wherever focus is
+    PreviousTab,
+    /// Request a context menu for the focused entity, see [`NavContextMenu`](crate::prelude::NavContextMenu)
+    ContextMenu,
 }
 
 impl ActionType {
@@ -36,6 +42,82 @@ pub enum GamepadStick {
     Right,
 }
 
+/// Shape of the deadzone applied to a gamepad stick. The size of the deadzone is the `stick_tolerance` of the
+/// [`UiNavInputManager`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum StickDeadzone {
+    /// Each axis is ignored while its own value is inside the deadzone.
+    #[default]
+    Axial,
+    /// The stick is ignored while its distance from the center is inside the deadzone.
+    Radial,
+    /// Like [`StickDeadzone::Radial`], but each axis is also ignored while it is inside a deadzone that grows with the
+    /// value of the other axis. This suppresses small perpendicular drift when pushing the stick along one axis.
+    Sloped,
+}
+
+/// Describes how a gamepad stick direction is divided into navigation directions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum StickSectors {
+    /// Snap to the dominant axis when both axes have a similar value, using the `stick_snap_tolerance` of the
+    /// [`UiNavInputManager`].
+    #[default]
+    Snap,
+    /// Only move along the dominant axis.
+    FourWay,
+    /// Move diagonally when the stick is within `diagonal_width` degrees of a diagonal, otherwise along the dominant
+    /// axis.
+    EightWay { diagonal_width: FloatOrd },
+}
+
+impl StickSectors {
+    /// Creates [`StickSectors::EightWay`] with a diagonal wedge of `diagonal_width` degrees.
+    pub const fn eight_way(diagonal_width: f32) -> Self {
+        Self::EightWay {
+            diagonal_width: FloatOrd(diagonal_width),
+        }
+    }
+}
+
+/// Modifier keys that must be held for an [`InputMapping::KeyChord`]. Either the left or right key satisfies a
+/// modifier, and modifiers that are not required must not be held.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub struct KeyModifiers {
+    pub control: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl KeyModifiers {
+    pub const SHIFT: Self = Self {
+        control: false,
+        shift: true,
+        alt: false,
+    };
+
+    pub const CONTROL: Self = Self {
+        control: true,
+        shift: false,
+        alt: false,
+    };
+
+    pub const CONTROL_SHIFT: Self = Self {
+        control: true,
+        shift: true,
+        alt: false,
+    };
+
+    /// Returns whether exactly the required modifiers are held.
+    pub fn matches(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) == self.control
+            && keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) == self.shift
+            && keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) == self.alt
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Debug, PartialEq, Hash)]
 pub enum InputMapping {
@@ -43,6 +125,12 @@ pub enum InputMapping {
         keycode: KeyCode,
         action: ActionType,
     },
+    /// A key that only triggers its action while the modifier keys are held, such as `Ctrl+Tab`.
+    KeyChord {
+        modifiers: KeyModifiers,
+        keycode: KeyCode,
+        action: ActionType,
+    },
     GamepadButton {
         gamepad: Option<Entity>,
         button: GamepadButton,
@@ -51,9 +139,24 @@ pub enum InputMapping {
     GamepadAxes {
         gamepad: Option<Entity>,
         stick: GamepadStick,
+        deadzone: StickDeadzone,
+        sectors: StickSectors,
     },
 }
 
+impl InputMapping {
+    /// Creates an [`InputMapping::GamepadAxes`] with the default [`StickDeadzone::Axial`] deadzone and
+    /// [`StickSectors::Snap`] sectors.
+    pub const fn gamepad_stick(gamepad: Option<Entity>, stick: GamepadStick) -> Self {
+        Self::GamepadAxes {
+            gamepad,
+            stick,
+            deadzone: StickDeadzone::Axial,
+            sectors: StickSectors::Snap,
+        }
+    }
+}
+
 #[derive(Debug, Resource, Reflect)]
 #[reflect(Resource, Debug)]
 pub struct UiNavInputManager {
@@ -63,6 +166,8 @@ pub struct UiNavInputManager {
     pub(crate) current_direction: Option<UiNavDirection>,
     pub(crate) stick_tolerance: f32,
     pub(crate) stick_snap_tolerance: f32,
+    /// Whether the state is driven externally instead of polling gamepads in `update_input_manager`
+    pub(crate) is_external: bool,
 }
 
 impl Default for UiNavInputManager {
@@ -84,9 +189,76 @@ impl UiNavInputManager {
             current_direction: None,
             stick_tolerance,
             stick_snap_tolerance,
+            is_external: false,
+        }
+    }
+
+    /// Adds mappings to the input map, such as `Q`/`E` bindings for the [`ActionType::PreviousTab`] and
+    /// [`ActionType::NextTab`] actions.
+    pub fn with_mappings(mut self, mappings: &[InputMapping]) -> Self {
+        self.input_map.extend_from_slice(mappings);
+        self
+    }
+
+    /// Creates an input manager without an input map that is driven externally via [`Self::update_from_pressed`],
+    /// instead of polling the keyboard and gamepads itself.
+    pub fn external() -> Self {
+        Self {
+            is_external: true,
+            ..Self::from_input_map(&[], 0.1, 0.9)
         }
     }
 
+    /// Returns whether the input manager is driven externally.
+    pub fn is_external(&self) -> bool {
+        self.is_external
+    }
+
+    /// Updates the input state from the actions that are currently pressed. This should be called once per frame
+    /// before the UI navigation systems run when the input manager is driven externally.
+    pub fn update_from_pressed(&mut self, pressed: impl IntoIterator<Item = ActionType>) {
+        self.begin_update();
+        for action in pressed {
+            self.current_state.insert(action, true);
+        }
+        self.update_direction();
+    }
+
+    /// Moves the current state into the previous state and clears the current state.
+    fn begin_update(&mut self) {
+        self.previous_state.clone_from(&self.current_state);
+        for v in self.current_state.values_mut() {
+            *v = false;
+        }
+    }
+
+    /// Sets the current direction from the pressed direction actions.
+    fn update_direction(&mut self) {
+        let left = self.pressed(ActionType::Left);
+        let right = self.pressed(ActionType::Right);
+        let up = self.pressed(ActionType::Up);
+        let down = self.pressed(ActionType::Down);
+        self.current_direction = if down && left {
+            Some(UiNavDirection::DownLeft)
+        } else if down && right {
+            Some(UiNavDirection::DownRight)
+        } else if up && left {
+            Some(UiNavDirection::UpLeft)
+        } else if up && right {
+            Some(UiNavDirection::UpRight)
+        } else if down {
+            Some(UiNavDirection::Down)
+        } else if up {
+            Some(UiNavDirection::Up)
+        } else if left {
+            Some(UiNavDirection::Left)
+        } else if right {
+            Some(UiNavDirection::Right)
+        } else {
+            None
+        };
+    }
+
     pub fn pressed(&self, action: ActionType) -> bool {
         self.current_state.get(&action).copied().unwrap_or(false)
     }
@@ -119,30 +291,70 @@ impl UiNavInputManager {
     }
 }
 
-fn get_gamepad_axes(
-    gamepad: &Gamepad,
-    stick: GamepadStick,
+fn get_gamepad_axes(gamepad: &Gamepad, stick: GamepadStick) -> Vec2 {
+    match stick {
+        GamepadStick::Left => gamepad.left_stick(),
+        GamepadStick::Right => gamepad.right_stick(),
+    }
+}
+
+/// Applies a deadzone to raw stick axes, then restricts the result to the directions allowed by `sectors`.
+///
+/// Each component of the result is either zero, or part of the direction the stick is pointing in.
+pub(crate) fn apply_stick_deadzone(
+    axes: Vec2,
+    deadzone: StickDeadzone,
+    sectors: StickSectors,
     stick_tolerance: f32,
     stick_snap_tolerance: f32,
 ) -> Vec2 {
-    let axes = match stick {
-        GamepadStick::Left => gamepad.left_stick(),
-        GamepadStick::Right => gamepad.right_stick(),
+    let length = axes.length();
+    let mut result = match deadzone {
+        StickDeadzone::Axial => Vec2::new(
+            if axes.x.abs() > stick_tolerance {
+                axes.x
+            } else {
+                0.
+            },
+            if axes.y.abs() > stick_tolerance {
+                axes.y
+            } else {
+                0.
+            },
+        ),
+        _ if length <= stick_tolerance => Vec2::ZERO,
+        StickDeadzone::Radial => axes,
+        StickDeadzone::Sloped => Vec2::new(
+            if axes.x.abs() > stick_tolerance * axes.y.abs() {
+                axes.x
+            } else {
+                0.
+            },
+            if axes.y.abs() > stick_tolerance * axes.x.abs() {
+                axes.y
+            } else {
+                0.
+            },
+        ),
     };
 
-    let abs_x = axes.x.abs();
-    let abs_y = axes.y.abs();
-    let mut result = Vec2::new(
-        if abs_x > stick_tolerance { axes.x } else { 0. },
-        if abs_y > stick_tolerance { axes.y } else { 0. },
-    );
-
-    // Clear small values when moving diagonlly. For example, the user may think they are pressing to the left, but
-    // there could be a small up/down value that makes the navigation feel wrong.
-    if abs_x > stick_tolerance
-        && abs_y > stick_tolerance
-        && (abs_x - abs_y).abs() < stick_snap_tolerance
-    {
+    if result.x == 0. || result.y == 0. {
+        return result;
+    }
+
+    let abs_x = result.x.abs();
+    let abs_y = result.y.abs();
+    let is_diagonal = match sectors {
+        // Clear small values when moving diagonlly. For example, the user may think they are pressing to the left,
+        // but there could be a small up/down value that makes the navigation feel wrong.
+        StickSectors::Snap => (abs_x - abs_y).abs() >= stick_snap_tolerance,
+        StickSectors::FourWay => false,
+        StickSectors::EightWay { diagonal_width } => {
+            let angle = abs_y.atan2(abs_x).to_degrees();
+            (angle - 45.).abs() <= diagonal_width.0 / 2.
+        }
+    };
+    if !is_diagonal {
         if abs_x > abs_y {
             result.y = 0.;
         } else {
@@ -153,17 +365,27 @@ fn get_gamepad_axes(
     result
 }
 
+/// Returns whether input from `entity` is accepted by a mapping for `gamepad`.
+pub(crate) fn gamepad_matches(
+    entity: Entity,
+    gamepad: Option<Entity>,
+    ownership: &UiNavGamepadOwnership,
+) -> bool {
+    match gamepad {
+        Some(gamepad) => entity == gamepad,
+        None => ownership.accepts(entity),
+    }
+}
+
 pub fn update_input_manager(
     input: &mut UiNavInputManager,
     gamepads: &Query<(Entity, &Gamepad)>,
+    ownership: &UiNavGamepadOwnership,
     // gamepad_buttons: &ButtonInput<GamepadButton>,
     // gamepad_axis: &Axis<GamepadAxis>,
 ) {
     // update the previous state, and clear current state
-    input.previous_state.clone_from(&input.current_state);
-    for v in input.current_state.values_mut() {
-        *v = false;
-    }
+    input.begin_update();
 
     // update the current state
     for action in input.input_map.iter() {
@@ -175,21 +397,28 @@ pub fn update_input_manager(
             } => {
                 let is_pressed = gamepads
                     .iter()
-                    .filter(|(e, _)| gamepad.is_none() || Some(*e) == *gamepad)
+                    .filter(|(e, _)| gamepad_matches(*e, *gamepad, ownership))
+                    .filter(|(e, _)| !ownership.is_suppressed(*e, *button))
                     .any(|(_, g)| g.pressed(*button));
 
                 if is_pressed {
                     input.current_state.insert(*action, is_pressed);
                 }
             }
-            InputMapping::GamepadAxes { gamepad, stick } => {
+            InputMapping::GamepadAxes {
+                gamepad,
+                stick,
+                deadzone,
+                sectors,
+            } => {
                 let axes = gamepads
                     .iter()
-                    .filter(|(e, _)| gamepad.is_none() || Some(*e) == *gamepad)
+                    .filter(|(e, _)| gamepad_matches(*e, *gamepad, ownership))
                     .map(|(_, g)| {
-                        get_gamepad_axes(
-                            g,
-                            *stick,
+                        apply_stick_deadzone(
+                            get_gamepad_axes(g, *stick),
+                            *deadzone,
+                            *sectors,
                             input.stick_tolerance,
                             input.stick_snap_tolerance,
                         )
@@ -204,14 +433,14 @@ pub fn update_input_manager(
                         }
                     });
 
-                if axes.x > input.stick_tolerance {
+                if axes.x > 0. {
                     input.current_state.insert(ActionType::Right, true);
-                } else if axes.x < -input.stick_tolerance {
+                } else if axes.x < 0. {
                     input.current_state.insert(ActionType::Left, true);
                 }
-                if axes.y > input.stick_tolerance {
+                if axes.y > 0. {
                     input.current_state.insert(ActionType::Up, true);
-                } else if axes.y < -input.stick_tolerance {
+                } else if axes.y < 0. {
                     input.current_state.insert(ActionType::Down, true);
                 }
             }
@@ -220,27 +449,106 @@ pub fn update_input_manager(
     }
 
     // Set current direction
-    let left = input.pressed(ActionType::Left);
-    let right = input.pressed(ActionType::Right);
-    let up = input.pressed(ActionType::Up);
-    let down = input.pressed(ActionType::Down);
-    input.current_direction = if down && left {
-        Some(UiNavDirection::DownLeft)
-    } else if down && right {
-        Some(UiNavDirection::DownRight)
-    } else if up && left {
-        Some(UiNavDirection::UpLeft)
-    } else if up && right {
-        Some(UiNavDirection::UpRight)
-    } else if down {
-        Some(UiNavDirection::Down)
-    } else if up {
-        Some(UiNavDirection::Up)
-    } else if left {
-        Some(UiNavDirection::Left)
-    } else if right {
-        Some(UiNavDirection::Right)
-    } else {
-        None
-    };
+    input.update_direction();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 0.2;
+    const SNAP_TOLERANCE: f32 = 0.9;
+
+    fn apply(axes: Vec2, deadzone: StickDeadzone, sectors: StickSectors) -> Vec2 {
+        apply_stick_deadzone(axes, deadzone, sectors, TOLERANCE, SNAP_TOLERANCE)
+    }
+
+    #[test]
+    fn axial_deadzone_ignores_each_axis() {
+        let result = apply(
+            Vec2::new(0.15, 0.15),
+            StickDeadzone::Axial,
+            StickSectors::eight_way(90.),
+        );
+        assert_eq!(result, Vec2::ZERO);
+
+        let result = apply(
+            Vec2::new(0.5, 0.15),
+            StickDeadzone::Axial,
+            StickSectors::eight_way(90.),
+        );
+        assert_eq!(result, Vec2::new(0.5, 0.));
+    }
+
+    #[test]
+    fn radial_deadzone_uses_distance() {
+        // Outside the axial deadzone on neither axis, but outside the radial deadzone
+        let result = apply(
+            Vec2::new(0.15, 0.15),
+            StickDeadzone::Radial,
+            StickSectors::eight_way(90.),
+        );
+        assert_eq!(result, Vec2::new(0.15, 0.15));
+
+        let result = apply(
+            Vec2::new(0.1, 0.1),
+            StickDeadzone::Radial,
+            StickSectors::eight_way(90.),
+        );
+        assert_eq!(result, Vec2::ZERO);
+    }
+
+    #[test]
+    fn sloped_deadzone_ignores_perpendicular_drift() {
+        let result = apply(
+            Vec2::new(0.9, 0.15),
+            StickDeadzone::Sloped,
+            StickSectors::eight_way(90.),
+        );
+        assert_eq!(result, Vec2::new(0.9, 0.));
+
+        let result = apply(
+            Vec2::new(0.9, 0.5),
+            StickDeadzone::Sloped,
+            StickSectors::eight_way(90.),
+        );
+        assert_eq!(result, Vec2::new(0.9, 0.5));
+    }
+
+    #[test]
+    fn four_way_sectors_keep_dominant_axis() {
+        let result = apply(
+            Vec2::new(-0.6, 0.7),
+            StickDeadzone::Radial,
+            StickSectors::FourWay,
+        );
+        assert_eq!(result, Vec2::new(0., 0.7));
+    }
+
+    #[test]
+    fn eight_way_sectors_use_diagonal_width() {
+        // ~40 degrees, inside a 30 degree wedge around the diagonal
+        let axes = Vec2::from_angle(40_f32.to_radians());
+        let result = apply(axes, StickDeadzone::Radial, StickSectors::eight_way(30.));
+        assert_eq!(result, axes);
+
+        // ~25 degrees, outside a 30 degree wedge around the diagonal
+        let axes = Vec2::from_angle(25_f32.to_radians());
+        let result = apply(axes, StickDeadzone::Radial, StickSectors::eight_way(30.));
+        assert_eq!(result, Vec2::new(axes.x, 0.));
+
+        // but inside a 60 degree wedge
+        let result = apply(axes, StickDeadzone::Radial, StickSectors::eight_way(60.));
+        assert_eq!(result, axes);
+    }
+
+    #[test]
+    fn snap_sectors_match_previous_behavior() {
+        let result = apply(
+            Vec2::new(0.7, -0.6),
+            StickDeadzone::Axial,
+            StickSectors::Snap,
+        );
+        assert_eq!(result, Vec2::new(0.7, 0.));
+    }
 }
@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+use leafwing_input_manager::prelude::*;
+
+use crate::{input::*, plugin::handle_gamepad_input};
+
+/// Plugin that drives [`UiNavInputManager`] from a leafwing-input-manager `ActionState<A>` resource instead of the
+/// built-in keyboard and gamepad polling.
+///
+/// Each app action is mapped onto an [`ActionType`]. Several app actions may map onto the same [`ActionType`], in
+/// which case it is pressed while any of them are pressed.
+///
+/// The app is responsible for adding the `InputManagerPlugin<A>` and the `ActionState<A>` and `InputMap<A>`
+/// resources.
+pub struct UiNavLeafwingPlugin<A: Actionlike> {
+    actions: Vec<(A, ActionType)>,
+}
+
+impl<A: Actionlike> UiNavLeafwingPlugin<A> {
+    pub fn new(actions: impl IntoIterator<Item = (A, ActionType)>) -> Self {
+        Self {
+            actions: actions.into_iter().collect(),
+        }
+    }
+}
+
+impl<A: Actionlike> Plugin for UiNavLeafwingPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(UiNavInputManager::external())
+            .insert_resource(UiNavLeafwingActionMap::<A> {
+                actions: self.actions.clone(),
+            })
+            .add_systems(
+                Update,
+                update_input_manager_from_action_state::<A>.before(handle_gamepad_input),
+            );
+    }
+}
+
+/// Resource mapping app actions onto UI navigation actions.
+#[derive(Resource, Debug)]
+struct UiNavLeafwingActionMap<A: Actionlike> {
+    actions: Vec<(A, ActionType)>,
+}
+
+/// System that updates the [`UiNavInputManager`] from the pressed actions in `ActionState<A>`.
+fn update_input_manager_from_action_state<A: Actionlike>(
+    action_state: Option<Res<ActionState<A>>>,
+    action_map: Res<UiNavLeafwingActionMap<A>>,
+    mut input_manager: ResMut<UiNavInputManager>,
+) {
+    let pressed = action_state
+        .as_ref()
+        .map(|action_state| {
+            action_map
+                .actions
+                .iter()
+                .filter(|(action, _)| action_state.pressed(action))
+                .map(|(_, action_type)| *action_type)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    input_manager.update_from_pressed(pressed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::NavMenu, events::NavRequest, test_utils::*, types::UiNavDirection};
+
+    #[derive(Actionlike, Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+    enum TestAction {
+        MoveDown,
+        Confirm,
+    }
+
+    #[test]
+    fn action_state_drives_navigation() {
+        let mut app = test_app();
+        app.add_plugins(UiNavLeafwingPlugin::new([
+            (TestAction::MoveDown, ActionType::Down),
+            (TestAction::Confirm, ActionType::Action),
+        ]))
+        .init_resource::<ActionState<TestAction>>();
+        spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        app.update();
+        app.update();
+        clear_events::<NavRequest>(&mut app);
+
+        app.world_mut()
+            .resource_mut::<ActionState<TestAction>>()
+            .press(&TestAction::MoveDown);
+        app.update();
+        assert_eq!(
+            read_events::<NavRequest>(&app),
+            vec![NavRequest::Movement(UiNavDirection::Down)]
+        );
+
+        clear_events::<NavRequest>(&mut app);
+        let mut action_state = app.world_mut().resource_mut::<ActionState<TestAction>>();
+        action_state.release(&TestAction::MoveDown);
+        action_state.press(&TestAction::Confirm);
+        app.update();
+        // the focus change of the movement also sends a refresh
+        assert!(read_events::<NavRequest>(&app).contains(&NavRequest::ActionPress));
+    }
+}
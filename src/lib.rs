@@ -1,19 +1,50 @@
+mod commands;
 mod components;
+mod conditions;
+mod context_menu;
+mod cycler;
+mod debug;
 mod default_input_map;
+mod dropdown;
 mod event_reader;
 mod events;
+mod focus_cursor;
 mod focus_node;
+mod gamepad_ownership;
 mod input;
+#[cfg(feature = "leafwing")]
+mod leafwing;
+mod movement_trace;
+mod observers;
 mod plugin;
+mod popup;
 mod resources;
+mod slider;
 mod spatial_map;
+mod style;
+mod tabs;
+#[cfg(test)]
+mod test_utils;
+mod text_input;
+mod toggle;
+mod tooltip;
 mod types;
+mod ui_nav;
 mod utils;
+mod validation;
+mod virtual_list;
 
 pub mod prelude {
     pub use crate::{
-        components::*, event_reader::*, events::*, input::*, plugin::*, resources::*, types::*,
+        commands::*, components::*, conditions::*, context_menu::*, cycler::*, debug::*,
+        dropdown::*, event_reader::*, events::*, focus_cursor::*, focus_node::*,
+        gamepad_ownership::*, input::*, movement_trace::*, observers::*, plugin::*, popup::*,
+        resources::*, slider::*, style::*, tabs::*, text_input::*, toggle::*, tooltip::*, types::*,
+        ui_nav::*, validation::*, virtual_list::*,
     };
+
+    #[cfg(feature = "leafwing")]
+    pub use crate::leafwing::*;
 }
 
 #[cfg(test)]
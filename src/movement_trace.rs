@@ -0,0 +1,148 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::{
+    focus_node::{FocusNode, FocusTarget},
+    types::UiNavDirection,
+};
+
+/// The decision made for a movement, from the focused node to the nearest target or the furthest target when wrapping.
+#[derive(Debug, Clone)]
+pub struct NavMovementTrace {
+    pub direction: UiNavDirection,
+    /// The focusable the movement started from
+    pub origin: Entity,
+    pub origin_node: FocusNode,
+    /// The menu of the origin, whose focusables are the candidates
+    pub menu: Option<Entity>,
+    /// Whether the menu wraps, which allows candidates along the axis in either direction
+    pub is_wrap: bool,
+    /// Every other focusable in the menu, including those that are not in the direction or along the axis
+    pub candidates: Vec<FocusTarget>,
+    /// The nearest candidate in the direction of the movement
+    pub nearest: Option<Entity>,
+    /// The furthest candidate in the opposite direction, used when the menu wraps and there is no nearest candidate
+    pub furthest: Option<Entity>,
+}
+
+impl NavMovementTrace {
+    /// Returns the focusable that focus moves to, if any.
+    pub fn target(&self) -> Option<Entity> {
+        self.nearest.or(self.furthest.filter(|_| self.is_wrap))
+    }
+
+    /// Returns whether focus wraps around to the furthest candidate.
+    pub fn is_wrapped(&self) -> bool {
+        self.nearest.is_none() && self.target().is_some()
+    }
+
+    /// Returns the candidate for a focusable.
+    pub fn candidate(&self, entity: Entity) -> Option<&FocusTarget> {
+        self.candidates
+            .iter()
+            .find(|candidate| candidate.entity == entity)
+    }
+}
+
+/// Opt-in resource recording a [`NavMovementTrace`] for each applied [`NavRequest::Movement`], which is useful for
+/// finding why a movement goes to an unexpected focusable.
+///
+/// Insert it to start recording: `app.init_resource::<UiNavMovementTraces>()`. Movement claimed by a
+/// `NavCaptureMovement` or blocked by a lock is not recorded.
+///
+/// [`NavRequest::Movement`]: crate::prelude::NavRequest::Movement
+#[derive(Resource, Debug)]
+pub struct UiNavMovementTraces {
+    /// Number of traces kept, dropping the oldest first
+    pub capacity: usize,
+    /// Whether each trace is also logged
+    pub log: bool,
+    traces: VecDeque<NavMovementTrace>,
+}
+
+impl Default for UiNavMovementTraces {
+    fn default() -> Self {
+        Self {
+            capacity: 16,
+            log: false,
+            traces: VecDeque::new(),
+        }
+    }
+}
+
+impl UiNavMovementTraces {
+    /// Sets the `log` value to `true` and returns the `UiNavMovementTraces`.
+    pub fn logged(mut self) -> Self {
+        self.log = true;
+        self
+    }
+
+    /// Iterates over the recorded traces, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &NavMovementTrace> {
+        self.traces.iter()
+    }
+
+    /// Returns the most recent trace.
+    pub fn last(&self) -> Option<&NavMovementTrace> {
+        self.traces.back()
+    }
+
+    pub fn clear(&mut self) {
+        self.traces.clear();
+    }
+
+    pub(crate) fn push(&mut self, trace: NavMovementTrace) {
+        if self.log {
+            info!(
+                "UI nav movement {:?} from {} in menu {:?}: nearest {:?}, furthest {:?}",
+                trace.direction, trace.origin, trace.menu, trace.nearest, trace.furthest
+            );
+            for candidate in trace.candidates.iter() {
+                info!("  {candidate:?}");
+            }
+        }
+        self.traces.push_back(trace);
+        while self.traces.len() > self.capacity {
+            self.traces.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::{components::*, test_utils::*};
+
+    #[test]
+    fn movements_are_traced() {
+        let mut app = test_app();
+        app.init_resource::<UiNavMovementTraces>();
+        let (_, buttons) = spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 3);
+        app.update();
+        app.update();
+
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        let traces = app.world().resource::<UiNavMovementTraces>();
+        let trace = traces.last().unwrap();
+        assert_eq!(trace.origin, buttons[0]);
+        assert_eq!(trace.candidates.len(), 2);
+        assert_eq!(trace.nearest, Some(buttons[1]));
+        assert_eq!(trace.target(), Some(buttons[1]));
+        let candidate = trace.candidate(buttons[2]).unwrap();
+        assert!(candidate.is_in_direction);
+        assert_relative_eq!(candidate.distance.total, 80.);
+
+        // moving up from the first button wraps around to the last
+        tap_key(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+        tap_key(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+        let traces = app.world().resource::<UiNavMovementTraces>();
+        assert_eq!(traces.iter().count(), 3);
+        let trace = traces.last().unwrap();
+        assert_eq!(trace.origin, buttons[0]);
+        assert!(trace.is_wrapped());
+        assert_eq!(trace.target(), Some(buttons[2]));
+    }
+}
@@ -0,0 +1,168 @@
+use bevy::{
+    ecs::{query::QueryData, traversal::Traversal},
+    prelude::*,
+};
+
+use crate::{components::NavMenu, events::*, types::UiNavInteractionType};
+
+/// Traversal used by the navigation observer events, which bubble up the `ChildOf` hierarchy and stop at the first
+/// [`NavMenu`].
+#[derive(QueryData)]
+pub struct NavMenuTraversal {
+    child_of: Option<&'static ChildOf>,
+    is_menu: Has<NavMenu>,
+}
+
+impl<D> Traversal<D> for NavMenuTraversal {
+    fn traverse(item: Self::Item<'_>, _data: &D) -> Option<Entity> {
+        if item.is_menu {
+            None
+        } else {
+            item.child_of.map(|child_of| child_of.parent())
+        }
+    }
+}
+
+/// Observer event triggered on a focusable when it is clicked.
+///
+/// Like the other navigation observer events, it is triggered alongside the buffered [`UiNavClickEvent`] and bubbles
+/// up to the ancestors of the focusable, up to and including its [`NavMenu`]. Use `entity` to find the focusable that
+/// was clicked from an observer on an ancestor.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[event(traversal = NavMenuTraversal, auto_propagate)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct NavClick {
+    pub entity: Entity,
+}
+
+/// Observer event triggered on a [`NavMenu`] when the "Cancel" key is pressed while it is the current menu.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[event(traversal = NavMenuTraversal, auto_propagate)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct NavCancel {
+    pub menu: Entity,
+}
+
+/// Observer event triggered on a focusable when it gains focus.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[event(traversal = NavMenuTraversal, auto_propagate)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct NavFocusGained {
+    pub entity: Entity,
+    pub interaction_type: UiNavInteractionType,
+    /// The focusable that had focus before, if any
+    pub previous: Option<Entity>,
+}
+
+/// Observer event triggered on a focusable when it loses focus.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[event(traversal = NavMenuTraversal, auto_propagate)]
+#[reflect(Debug, PartialEq, Hash)]
+pub struct NavFocusLost {
+    pub entity: Entity,
+    pub interaction_type: UiNavInteractionType,
+}
+
+/// System that triggers the observer events of the click, cancel and focus events sent this frame.
+pub(crate) fn trigger_nav_observers(
+    mut commands: Commands,
+    mut click_events: EventReader<UiNavClickEvent>,
+    mut cancel_events: EventReader<UiNavCancelEvent>,
+    mut focus_events: EventReader<UiNavFocusChangedEvent>,
+    mut focus_lost_events: EventReader<UiNavFocusLostEvent>,
+) {
+    for event in focus_lost_events.read() {
+        commands.trigger_targets(
+            NavFocusLost {
+                entity: event.entity,
+                interaction_type: event.interaction_type,
+            },
+            event.entity,
+        );
+    }
+    for event in focus_events.read() {
+        commands.trigger_targets(
+            NavFocusGained {
+                entity: event.entity,
+                interaction_type: event.interaction_type,
+                previous: event.previous,
+            },
+            event.entity,
+        );
+    }
+    for event in click_events.read() {
+        commands.trigger_targets(NavClick { entity: event.0 }, event.0);
+    }
+    for event in cancel_events.read() {
+        commands.trigger_targets(NavCancel { menu: event.0 }, event.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Resource recording the entities observers were triggered on.
+    #[derive(Resource, Default)]
+    struct Triggered(Vec<(&'static str, Entity)>);
+
+    fn record<E: Event>(name: &'static str) -> impl FnMut(Trigger<E>, ResMut<Triggered>) {
+        move |trigger, mut triggered| triggered.0.push((name, trigger.target()))
+    }
+
+    #[test]
+    fn observer_events_bubble_up_to_the_menu() {
+        let mut app = test_app();
+        app.init_resource::<Triggered>();
+        let root = app
+            .world_mut()
+            .spawn_empty()
+            .observe(record::<NavClick>("root click"))
+            .id();
+        let (menu, buttons) = spawn_column(
+            &mut app,
+            (ChildOf(root), NavMenu::default().prioritized()),
+            Vec2::ZERO,
+            2,
+        );
+        app.world_mut()
+            .entity_mut(menu)
+            .observe(record::<NavClick>("menu click"));
+        for button in buttons.iter() {
+            app.world_mut()
+                .entity_mut(*button)
+                .observe(record::<NavClick>("click"))
+                .observe(record::<NavFocusGained>("gained"))
+                .observe(record::<NavFocusLost>("lost"));
+        }
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Triggered>().0,
+            vec![("gained", buttons[0])]
+        );
+
+        app.world_mut().resource_mut::<Triggered>().0.clear();
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(
+            app.world().resource::<Triggered>().0,
+            vec![("lost", buttons[0]), ("gained", buttons[1])]
+        );
+
+        // clicks bubble up to the menu, but not past it
+        app.world_mut().resource_mut::<Triggered>().0.clear();
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert_eq!(
+            app.world().resource::<Triggered>().0,
+            vec![("click", buttons[1]), ("menu click", menu)]
+        );
+        assert!(!app
+            .world()
+            .resource::<Triggered>()
+            .0
+            .contains(&("root click", root)));
+    }
+}
@@ -1,17 +1,40 @@
 use bevy::{
-    input::{keyboard::KeyboardInput, mouse::MouseButtonInput, ButtonState},
+    ecs::entity::Entities,
+    input::{
+        common_conditions::input_just_released, keyboard::KeyboardInput, mouse::MouseButtonInput,
+        ButtonState,
+    },
+    platform::collections::HashSet,
     prelude::*,
     ui::RelativeCursorPosition,
 };
 
 use crate::{
+    commands::*,
     components::*,
+    context_menu::*,
+    cycler::*,
+    dropdown::*,
     events::*,
+    focus_cursor::*,
+    gamepad_ownership::*,
     input::*,
+    movement_trace::*,
+    observers::*,
+    popup::*,
     resources::*,
+    slider::*,
     spatial_map::{UiSpatialMap, UiSpatialMapEvent},
+    style::*,
+    tabs::*,
+    text_input::*,
+    toggle::*,
+    tooltip::*,
     types::*,
+    ui_nav::UiNavMenuStack,
     utils::*,
+    validation::*,
+    virtual_list::*,
 };
 
 pub struct BevyUiNavPlugin;
@@ -22,9 +45,31 @@ impl Plugin for BevyUiNavPlugin {
             .add_event::<UiNavCancelEvent>()
             .add_event::<NavRequest>()
             .add_event::<UiNavFocusChangedEvent>()
+            .add_event::<UiNavFocusLostEvent>()
+            .add_event::<UiNavMenuChangedEvent>()
+            .add_event::<UiNavCapturedMovementEvent>()
+            .add_event::<UiNavSliderChangedEvent>()
+            .add_event::<UiNavCyclerChangedEvent>()
+            .add_event::<UiNavDropdownChangedEvent>()
+            .add_event::<UiNavPopupClosedEvent>()
+            .add_event::<UiNavContextMenuEvent>()
+            .add_event::<UiNavTabChangedEvent>()
+            .add_event::<UiNavToggleChangedEvent>()
+            .add_event::<UiNavVirtualRowBoundEvent>()
+            .add_event::<UiNavRadioGroupChangedEvent>()
+            .add_event::<UiNavGamepadClaimedEvent>()
+            .add_event::<UiNavGamepadDisconnectedEvent>()
+            .add_event::<UiNavGamepadReconnectedEvent>()
+            .add_event::<UiNavTextInputChangedEvent>()
+            .add_event::<UiNavTextInputSubmitEvent>()
             .init_resource::<UiNavState>()
             .init_resource::<UiNavSettings>()
             .init_resource::<UiNavInputManager>()
+            .init_resource::<UiNavConsumedInput>()
+            .init_resource::<UiNavGamepadOwnership>()
+            .init_resource::<UiNavTooltipState>()
+            .init_resource::<UiNavMenuStack>()
+            .init_resource::<UiNavPendingFocus>()
             .add_systems(
                 Update,
                 (
@@ -36,11 +81,16 @@ impl Plugin for BevyUiNavPlugin {
                         )
                             .chain(),
                         (
+                            release_despawned_locks,
                             handle_interactions
                                 .run_if(on_event::<CursorMoved>.or(on_event::<MouseButtonInput>)),
+                            handle_gamepad_connections,
+                            handle_gamepad_claim,
                             handle_gamepad_input,
                             handle_keyboard_input_events.run_if(on_event::<KeyboardInput>),
                             handle_keyboard_input_presses,
+                            update_consumed_input,
+                            clear_consumed_input,
                         )
                             .chain(),
                         tick_pressed_timer,
@@ -48,14 +98,99 @@ impl Plugin for BevyUiNavPlugin {
                         handle_focusable_changed,
                     )
                         .before(UiNavSet),
-                    handle_nav_requests
-                        .run_if(on_event::<NavRequest>)
+                    apply_pending_focus
+                        .after(setup_new_focusables)
+                        .after(update_focusable_visibility)
+                        .before(UiNavSet),
+                    (
+                        handle_nav_requests.run_if(on_event::<NavRequest>),
+                        trigger_nav_observers,
+                        update_current_menu_marker.run_if(on_event::<UiNavMenuChangedEvent>),
+                        update_focus_markers,
+                    )
+                        .chain()
                         .in_set(UiNavSet),
+                    (
+                        handle_text_input_keyboard.run_if(on_event::<KeyboardInput>),
+                        handle_text_input_clicks.run_if(on_event::<UiNavClickEvent>),
+                        end_interrupted_text_input_edits,
+                        update_text_input_display,
+                    )
+                        .chain()
+                        .after(UiNavSet),
+                    (
+                        handle_slider_movement.run_if(on_event::<UiNavCapturedMovementEvent>),
+                        handle_slider_drag,
+                    )
+                        .after(UiNavSet),
+                    (
+                        handle_cycler_movement.run_if(on_event::<UiNavCapturedMovementEvent>),
+                        update_cycler_display,
+                    )
+                        .chain()
+                        .after(UiNavSet),
+                    (
+                        handle_dropdown_clicks.run_if(on_event::<UiNavClickEvent>),
+                        handle_popup_dismissal,
+                        handle_dropdown_popup_closed.run_if(on_event::<UiNavPopupClosedEvent>),
+                        update_dropdown_display,
+                    )
+                        .chain()
+                        .after(UiNavSet),
+                    (
+                        handle_context_menu_mouse.run_if(input_just_released(MouseButton::Right)),
+                        handle_context_menu_clicks.run_if(on_event::<UiNavClickEvent>),
+                        position_context_menus,
+                    )
+                        .chain()
+                        .after(UiNavSet),
+                    (
+                        handle_tab_requests
+                            .run_if(on_event::<NavRequest>.or(on_event::<UiNavClickEvent>)),
+                        update_tab_panels.run_if(
+                            any_match_filter::<Changed<NavTabs>>
+                                .or(any_match_filter::<Added<NavTab>>),
+                        ),
+                    )
+                        .chain()
+                        .after(UiNavSet),
+                    (
+                        handle_virtual_list_movement.run_if(on_event::<UiNavCapturedMovementEvent>),
+                        sync_virtual_list_rows,
+                    )
+                        .chain()
+                        .after(UiNavSet),
+                    (update_nav_style_targets, apply_nav_styles)
+                        .chain()
+                        .after(UiNavSet),
+                    update_focus_cursors.after(UiNavSet),
+                    (update_tooltip_target, update_tooltip_displays)
+                        .chain()
+                        .after(UiNavSet),
+                    (
+                        handle_toggle_clicks.run_if(on_event::<UiNavClickEvent>),
+                        update_radio_buttons.run_if(
+                            any_match_filter::<Changed<NavRadioGroup>>
+                                .or(any_match_filter::<Added<NavRadioButton>>),
+                        ),
+                    )
+                        .chain()
+                        .after(UiNavSet),
+                    warn_invalid_nav_graphs
+                        .run_if(|settings: Res<UiNavSettings>| settings.warn_invalid_nav_graphs)
+                        .after(UiNavSet),
                 ),
             );
     }
 }
 
+/// System that releases the navigation locks held by despawned entities, which can no longer unlock themselves.
+fn release_despawned_locks(entities: &Entities, mut nav_state: ResMut<UiNavState>) {
+    if nav_state.has_stale_locks(entities) {
+        nav_state.remove_stale_locks(entities);
+    }
+}
+
 /// System that initializes newly added menus
 fn tick_pressed_timer(time: Res<Time>, mut nav_state: ResMut<UiNavState>) {
     if nav_state.direction.is_some() {
@@ -70,6 +205,7 @@ fn setup_new_menus(
     menu_query: Query<(), With<NavMenu>>,
     mut nav_state: ResMut<UiNavState>,
     mut nav_request_writer: EventWriter<NavRequest>,
+    mut menu_change_writer: EventWriter<UiNavMenuChangedEvent>,
 ) {
     if query.is_empty() {
         return;
@@ -94,7 +230,12 @@ fn setup_new_menus(
     }
 
     // set focus to this menu if there is no current menu
-    if let Some((target, _)) = new_focus {
+    if let Some((target, _)) = new_focus.filter(|(target, _)| Some(*target) != nav_state.menu) {
+        menu_change_writer.write(UiNavMenuChangedEvent {
+            previous: nav_state.menu,
+            current: Some(target),
+            interaction_type: UiNavInteractionType::Auto,
+        });
         nav_state.menu = Some(target);
     }
 
@@ -179,6 +320,7 @@ fn setup_new_focusables(
 ///
 /// Interaction changes are only respected if the mouse was moved AND the new interaction state is
 /// `Interaction::Hovered`.
+#[allow(clippy::too_many_arguments)]
 fn handle_interactions(
     mut cursor_moved_events: EventReader<CursorMoved>,
     mut mouse_button_input_events: EventReader<MouseButtonInput>,
@@ -191,6 +333,7 @@ fn handle_interactions(
     menu_query: Query<&NavMenu>,
     mut nav_request_writer: EventWriter<NavRequest>,
     mut click_writer: EventWriter<UiNavClickEvent>,
+    mut focus_lost_writer: EventWriter<UiNavFocusLostEvent>,
     nav_state: Res<UiNavState>,
 ) {
     // only handle interaction changes when the mouse is moved
@@ -198,9 +341,9 @@ fn handle_interactions(
         return;
     }
 
-    // Exit if focus if locked
+    // Exit if the mouse is locked
     // IMPORTANT: Do this AFTER we consume the event readers
-    if nav_state.locked {
+    if nav_state.lock_scope().mouse {
         return;
     }
 
@@ -239,6 +382,12 @@ fn handle_interactions(
             Interaction::None => (false, false),
         };
         if focusable.is_mouse_only && !is_hovered && !is_pressed {
+            if focusable.is_focused {
+                focus_lost_writer.write(UiNavFocusLostEvent {
+                    entity,
+                    interaction_type: UiNavInteractionType::Mouse,
+                });
+            }
             focusable.is_focused = false;
             focusable.is_pressed_interaction = false;
             focusable.is_pressed_interaction_from_active = false;
@@ -279,11 +428,17 @@ fn handle_current_menu_removed(
     mut removed: RemovedComponents<NavMenu>,
     mut nav_state: ResMut<UiNavState>,
     mut nav_request_writer: EventWriter<NavRequest>,
+    mut menu_change_writer: EventWriter<UiNavMenuChangedEvent>,
 ) {
     for entity in removed.read() {
         if Some(entity) == nav_state.menu {
             nav_state.menu = None;
             nav_state.clear_direction();
+            menu_change_writer.write(UiNavMenuChangedEvent {
+                previous: Some(entity),
+                current: None,
+                interaction_type: UiNavInteractionType::Auto,
+            });
         }
     }
 
@@ -292,18 +447,22 @@ fn handle_current_menu_removed(
 
 /// System that listens for keyboard or gamepad input and emits the appropriate navigation events.
 #[allow(clippy::too_many_arguments)]
-fn handle_gamepad_input(
+pub(crate) fn handle_gamepad_input(
     gamepads: Query<(Entity, &Gamepad)>,
     mut nav_request_writer: EventWriter<NavRequest>,
     mut nav_state: ResMut<UiNavState>,
     settings: Res<UiNavSettings>,
     mut input_manager: ResMut<UiNavInputManager>,
+    ownership: Res<UiNavGamepadOwnership>,
 ) {
-    update_input_manager(&mut input_manager, &gamepads);
+    if !input_manager.is_external {
+        update_input_manager(&mut input_manager, &gamepads, &ownership);
+    }
 
-    if nav_state.menu.is_some() && !nav_state.locked {
+    let lock_scope = nav_state.lock_scope();
+    if nav_state.menu.is_some() && !(lock_scope.movement && lock_scope.actions) {
         // send movement event
-        if let Some(direction) = input_manager.direction() {
+        if let Some(direction) = input_manager.direction().filter(|_| !lock_scope.movement) {
             if nav_state.direction.is_none() {
                 // send movement key on first pressed
                 nav_request_writer.write(NavRequest::Movement(direction));
@@ -326,10 +485,25 @@ fn handle_gamepad_input(
         }
 
         // send action press event
-        if input_manager.just_pressed(ActionType::Action) {
-            nav_request_writer.write(NavRequest::ActionPress);
-        } else if input_manager.just_released(ActionType::Action) {
-            nav_request_writer.write(NavRequest::ActionRelease);
+        if !lock_scope.actions {
+            if input_manager.just_pressed(ActionType::Action) {
+                nav_request_writer.write(NavRequest::ActionPress);
+            } else if input_manager.just_released(ActionType::Action) {
+                nav_request_writer.write(NavRequest::ActionRelease);
+            }
+        }
+
+        // send tab and context menu events
+        for action in [
+            ActionType::NextTab,
+            ActionType::PreviousTab,
+            ActionType::ContextMenu,
+        ] {
+            if input_manager.just_pressed(action) && !lock_scope.actions {
+                if let Some(nav_request) = just_pressed_request(action) {
+                    nav_request_writer.write(nav_request);
+                }
+            }
         }
     } else if nav_state.direction.is_some() {
         // clear direction keys when the menu is locked, or we don't have a current menu
@@ -356,10 +530,16 @@ fn handle_nav_requests(
         &InheritedVisibility,
     )>,
     menu_query: Query<(Entity, &NavMenu)>,
+    capture_query: Query<&NavCaptureMovement>,
     mut cancel_writer: EventWriter<UiNavCancelEvent>,
     mut nav_state: ResMut<UiNavState>,
     mut click_writer: EventWriter<UiNavClickEvent>,
     mut focus_change_writer: EventWriter<UiNavFocusChangedEvent>,
+    mut focus_lost_writer: EventWriter<UiNavFocusLostEvent>,
+    mut menu_change_writer: EventWriter<UiNavMenuChangedEvent>,
+    mut captured_movement_writer: EventWriter<UiNavCapturedMovementEvent>,
+    mut context_menu_writer: EventWriter<UiNavContextMenuEvent>,
+    mut movement_traces: Option<ResMut<UiNavMovementTraces>>,
 ) {
     let mut spatial_map = UiSpatialMap::new(&menu_query, &query.as_readonly(), &nav_state);
 
@@ -373,7 +553,27 @@ fn handle_nav_requests(
                 spatial_map.set_focus(*entity, *interaction_type);
             }
             NavRequest::Movement(direction) => {
-                spatial_map.apply_movement(*direction);
+                // Let the focused entity claim the movement instead of moving focus
+                let capture_entity = spatial_map.focusable().filter(|entity| {
+                    capture_query
+                        .get(*entity)
+                        .is_ok_and(|capture| capture.captures(*direction))
+                });
+                match capture_entity {
+                    Some(entity) if spatial_map.can_move() => {
+                        captured_movement_writer.write(UiNavCapturedMovementEvent {
+                            entity,
+                            direction: *direction,
+                        });
+                    }
+                    Some(_) => (),
+                    None => {
+                        let trace = spatial_map.apply_movement(*direction);
+                        if let (Some(trace), Some(traces)) = (trace, movement_traces.as_mut()) {
+                            traces.push(trace);
+                        }
+                    }
+                }
             }
             NavRequest::ActionPress => {
                 spatial_map.press();
@@ -385,12 +585,32 @@ fn handle_nav_requests(
                 is_cancel = true;
             }
             NavRequest::Lock => {
-                spatial_map.lock();
+                nav_state.add_lock(NavLockReason::Default, NavLockScope::ALL);
+                spatial_map.set_lock_scope(nav_state.lock_scope());
             }
             NavRequest::Unlock => {
-                spatial_map.unlock();
+                nav_state.remove_lock(NavLockReason::Default);
+                spatial_map.set_lock_scope(nav_state.lock_scope());
+            }
+            NavRequest::LockWith { reason, scope } => {
+                nav_state.add_lock(*reason, *scope);
+                spatial_map.set_lock_scope(nav_state.lock_scope());
+            }
+            NavRequest::UnlockWith(reason) => {
+                nav_state.remove_lock(*reason);
+                spatial_map.set_lock_scope(nav_state.lock_scope());
             }
             NavRequest::Refresh => (),
+            // Handled by the `NavTabs` systems
+            NavRequest::NextTab | NavRequest::PreviousTab => (),
+            NavRequest::ContextMenu => {
+                if let (true, Some(entity)) = (spatial_map.can_act(), spatial_map.focusable()) {
+                    context_menu_writer.write(UiNavContextMenuEvent {
+                        entity,
+                        anchor: NavContextMenuAnchor::Owner,
+                    });
+                }
+            }
         }
     }
 
@@ -413,18 +633,37 @@ fn handle_nav_requests(
     }
 
     // Focus on new menu
-    if let Some(new_menu) = spatial_map.get_new_menu() {
+    let previous_menu = nav_state.menu;
+    if let Some((new_menu, interaction_type)) = spatial_map.get_new_menu() {
         nav_state.menu = new_menu;
+        menu_change_writer.write(UiNavMenuChangedEvent {
+            previous: previous_menu,
+            current: new_menu,
+            interaction_type,
+        });
     }
 
     // Focus on new focusable
     if let Some((new_focusable, interaction_type)) = spatial_map.get_new_focusable() {
+        let previous = query
+            .iter()
+            .find(|(_, focusable, ..)| focusable.is_focused && !focusable.is_mouse_only)
+            .map(|(entity, ..)| entity);
         for (entity, mut focusable, _, _, _) in query.iter_mut() {
+            let was_focused = focusable.is_focused;
             focusable.is_focused = Some(entity) == new_focusable;
+            if was_focused && !focusable.is_focused {
+                focus_lost_writer.write(UiNavFocusLostEvent {
+                    entity,
+                    interaction_type,
+                });
+            }
             if focusable.is_focused {
                 focus_change_writer.write(UiNavFocusChangedEvent {
                     entity,
                     interaction_type,
+                    previous,
+                    previous_menu,
                 });
             }
         }
@@ -432,30 +671,128 @@ fn handle_nav_requests(
 
     // Focus on new mouse-only focusable
     if let Some(new_focusable) = spatial_map.get_new_mouse_only_focusable() {
+        let previous = query
+            .iter()
+            .find(|(_, focusable, ..)| focusable.is_focused && focusable.is_mouse_only)
+            .map(|(entity, ..)| entity);
         for (entity, mut focusable, _, _, _) in query.iter_mut() {
             if focusable.is_mouse_only {
+                let was_focused = focusable.is_focused;
                 focusable.is_focused = Some(entity) == new_focusable;
+                if was_focused && !focusable.is_focused {
+                    focus_lost_writer.write(UiNavFocusLostEvent {
+                        entity,
+                        interaction_type: UiNavInteractionType::Mouse,
+                    });
+                }
                 if focusable.is_focused {
                     focus_change_writer.write(UiNavFocusChangedEvent {
                         entity,
                         interaction_type: UiNavInteractionType::Mouse,
+                        previous,
+                        previous_menu,
                     });
                 }
             }
         }
     }
 
-    // Handle new locked state
-    if let Some(locked) = spatial_map.get_new_locked() {
-        nav_state.locked = locked
-    }
-
     // Handle cancel events
-    if let (false, true, Some(menu)) = (nav_state.locked, is_cancel, spatial_map.menu()) {
+    if let (false, true, Some(menu)) = (
+        nav_state.lock_scope().actions,
+        is_cancel,
+        spatial_map.menu(),
+    ) {
         cancel_writer.write(UiNavCancelEvent(menu));
     }
 }
 
+/// System that moves the [`CurrentNavMenu`] marker to the current menu when it changes.
+fn update_current_menu_marker(
+    mut commands: Commands,
+    mut events: EventReader<UiNavMenuChangedEvent>,
+) {
+    for event in events.read() {
+        if let Some(previous) = event.previous {
+            commands.entity(previous).try_remove::<CurrentNavMenu>();
+        }
+        if let Some(current) = event.current {
+            commands.entity(current).try_insert(CurrentNavMenu);
+        }
+    }
+}
+
+/// Inserts `T` on an entity if `is_set` and it does not have it, or removes it if it does.
+fn set_marker<T: Component + Default>(
+    commands: &mut Commands,
+    entity: Entity,
+    has: bool,
+    is_set: bool,
+) {
+    if is_set && !has {
+        commands.entity(entity).try_insert(T::default());
+    } else if !is_set && has {
+        commands.entity(entity).try_remove::<T>();
+    }
+}
+
+/// System that mirrors the state of changed focusables with the [`Focused`], [`NavPressed`], [`Hovered`] and
+/// [`NavDisabled`] markers, and moves the [`FocusWithin`] marker to the ancestors of the focused focusables.
+#[allow(clippy::type_complexity)]
+fn update_focus_markers(
+    mut commands: Commands,
+    changed_query: Query<
+        (
+            Entity,
+            &Focusable,
+            Has<Focused>,
+            Has<NavPressed>,
+            Has<Hovered>,
+            Has<NavDisabled>,
+        ),
+        Changed<Focusable>,
+    >,
+    mut removed: RemovedComponents<Focusable>,
+    focusable_query: Query<(Entity, &Focusable)>,
+    focus_within_query: Query<Entity, With<FocusWithin>>,
+    parent_query: Query<&ChildOf>,
+) {
+    let mut is_changed = false;
+    for (entity, focusable, is_focused, is_pressed, is_hovered, is_disabled) in changed_query.iter()
+    {
+        set_marker::<Focused>(&mut commands, entity, is_focused, focusable.active());
+        set_marker::<NavPressed>(&mut commands, entity, is_pressed, focusable.is_pressed());
+        set_marker::<Hovered>(&mut commands, entity, is_hovered, focusable.is_hovered());
+        set_marker::<NavDisabled>(&mut commands, entity, is_disabled, focusable.is_disabled);
+        is_changed = true;
+    }
+    for entity in removed.read() {
+        if let Ok(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.try_remove::<(Focused, NavPressed, Hovered, NavDisabled)>();
+        }
+        is_changed = true;
+    }
+    if !is_changed {
+        return;
+    }
+
+    let focus_within: HashSet<Entity> = focusable_query
+        .iter()
+        .filter(|(_, focusable)| focusable.active())
+        .flat_map(|(entity, _)| parent_query.iter_ancestors(entity))
+        .collect();
+    for entity in focus_within_query.iter() {
+        if !focus_within.contains(&entity) {
+            commands.entity(entity).try_remove::<FocusWithin>();
+        }
+    }
+    for entity in focus_within {
+        if !focus_within_query.contains(entity) {
+            commands.entity(entity).try_insert(FocusWithin);
+        }
+    }
+}
+
 /// System that refreshes the UI navigation state whenever a focusable changes.
 fn handle_focusable_changed(
     query: Query<(), Changed<Focusable>>,
@@ -552,11 +889,386 @@ fn handle_keyboard_input_presses(
                         None
                     }
                 }
+                ActionType::NextTab | ActionType::PreviousTab | ActionType::ContextMenu => {
+                    if keys.just_pressed(*keycode) && KeyModifiers::default().matches(&keys) {
+                        just_pressed_request(*action)
+                    } else {
+                        None
+                    }
+                }
                 _ => None,
             };
             if let Some(nav_request) = nav_request {
                 nav_request_writer.write(nav_request);
             }
+        } else if let InputMapping::KeyChord {
+            modifiers,
+            keycode,
+            action,
+        } = action
+        {
+            if keys.just_pressed(*keycode) && modifiers.matches(&keys) {
+                if let Some(nav_request) = just_pressed_request(*action) {
+                    nav_request_writer.write(nav_request);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the request for an action that is only handled when it is just pressed.
+fn just_pressed_request(action: ActionType) -> Option<NavRequest> {
+    match action {
+        ActionType::NextTab => Some(NavRequest::NextTab),
+        ActionType::PreviousTab => Some(NavRequest::PreviousTab),
+        ActionType::ContextMenu => Some(NavRequest::ContextMenu),
+        _ => None,
+    }
+}
+
+/// Returns whether input for `action` is blocked by navigation locks.
+fn is_action_locked(lock_scope: &NavLockScope, action: ActionType) -> bool {
+    match action {
+        ActionType::Up | ActionType::Down | ActionType::Left | ActionType::Right => {
+            lock_scope.movement
+        }
+        _ => lock_scope.actions,
+    }
+}
+
+/// System that records which actions were consumed by the UI this frame.
+fn update_consumed_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    nav_state: Res<UiNavState>,
+    input_manager: Res<UiNavInputManager>,
+    text_input_query: Query<&NavTextInput>,
+    mut consumed: ResMut<UiNavConsumedInput>,
+) {
+    consumed.actions.clear();
+    consumed.keys.clear();
+
+    // All keyboard input is consumed while a text input is being edited, even though navigation is locked
+    let is_editing = text_input_query.iter().any(NavTextInput::is_editing);
+    if is_editing {
+        consumed
+            .keys
+            .extend(keys.get_pressed().chain(keys.get_just_released()).copied());
+    }
+
+    // Input is only consumed while a menu is receiving it
+    let lock_scope = nav_state.lock_scope();
+    if nav_state.menu.is_none() {
+        return;
+    }
+
+    for mapping in input_manager.input_map.iter() {
+        let (keycode, action) = match mapping {
+            InputMapping::Key { keycode, action } => (keycode, action),
+            InputMapping::KeyChord {
+                modifiers,
+                keycode,
+                action,
+            } if modifiers.matches(&keys) => (keycode, action),
+            _ => continue,
+        };
+        if (keys.pressed(*keycode) || keys.just_released(*keycode))
+            && (is_editing || !is_action_locked(&lock_scope, *action))
+        {
+            consumed.actions.insert(*action);
         }
     }
+
+    let gamepad_actions = input_manager
+        .current_state
+        .keys()
+        .chain(input_manager.previous_state.keys())
+        .filter(|action| input_manager.pressed(**action) || input_manager.just_released(**action))
+        .filter(|action| !is_action_locked(&lock_scope, **action))
+        .copied()
+        .collect::<Vec<_>>();
+    consumed.actions.extend(gamepad_actions);
+}
+
+/// System that clears consumed inputs from `ButtonInput<KeyCode>` and `Gamepad` components when
+/// [`UiNavSettings::clear_consumed_input`] is enabled.
+///
+/// Only the "just pressed" and "just released" states are cleared. Clearing the "pressed" state would prevent the
+/// release from ever being reported.
+fn clear_consumed_input(
+    settings: Res<UiNavSettings>,
+    consumed: Res<UiNavConsumedInput>,
+    input_manager: Res<UiNavInputManager>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut gamepads: Query<(Entity, &mut Gamepad)>,
+    ownership: Res<UiNavGamepadOwnership>,
+) {
+    if !settings.clear_consumed_input || consumed.is_empty() {
+        return;
+    }
+
+    for key in consumed.keys.iter() {
+        keys.clear_just_pressed(*key);
+        keys.clear_just_released(*key);
+    }
+
+    for mapping in input_manager.input_map.iter() {
+        match mapping {
+            InputMapping::Key { keycode, action }
+            | InputMapping::KeyChord {
+                keycode, action, ..
+            } if consumed.consumed(*action) => {
+                keys.clear_just_pressed(*keycode);
+                keys.clear_just_released(*keycode);
+            }
+            InputMapping::GamepadButton {
+                gamepad,
+                button,
+                action,
+            } if consumed.consumed(*action) => {
+                for (_, mut g) in gamepads
+                    .iter_mut()
+                    .filter(|(e, _)| gamepad_matches(*e, *gamepad, &ownership))
+                {
+                    g.digital_mut().clear_just_pressed(*button);
+                    g.digital_mut().clear_just_released(*button);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn mouse_only_focus_is_lost_on_hover_out() {
+        let mut app = test_app();
+        let (menu, _) = spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 1);
+        let mouse_only = app
+            .world_mut()
+            .spawn((
+                Focusable::default().with_mouse_only(true),
+                focus_node(Vec2::new(0., 50.), Vec2::new(100., 20.)),
+                ChildOf(menu),
+            ))
+            .id();
+        #[derive(Resource, Default)]
+        struct Lost(Vec<Entity>);
+        app.init_resource::<Lost>();
+        app.world_mut().entity_mut(mouse_only).observe(
+            |trigger: Trigger<NavFocusLost>, mut lost: ResMut<Lost>| {
+                lost.0.push(trigger.event().entity);
+            },
+        );
+        app.update();
+        app.update();
+
+        set_interaction(&mut app, mouse_only, Interaction::Hovered);
+        app.update();
+        assert!(app.world().get::<Focusable>(mouse_only).unwrap().is_focused);
+        assert!(read_events::<UiNavFocusLostEvent>(&app).is_empty());
+
+        set_interaction(&mut app, mouse_only, Interaction::None);
+        assert!(!app.world().get::<Focusable>(mouse_only).unwrap().is_focused);
+        assert_eq!(
+            read_events::<UiNavFocusLostEvent>(&app),
+            vec![UiNavFocusLostEvent {
+                entity: mouse_only,
+                interaction_type: UiNavInteractionType::Mouse,
+            }]
+        );
+        app.update();
+        assert_eq!(app.world().resource::<Lost>().0, vec![mouse_only]);
+    }
+
+    #[test]
+    fn focus_events_include_previous_focus() {
+        let mut app = test_app();
+        let (menus, buttons) = spawn_menus(&mut app, 2, 1);
+        let buttons: Vec<Entity> = buttons.concat();
+        app.update();
+        app.update();
+        clear_events::<UiNavFocusChangedEvent>(&mut app);
+
+        // move focus to the button in the other menu
+        app.world_mut().send_event(NavRequest::SetFocus {
+            entity: buttons[1],
+            interaction_type: UiNavInteractionType::Manual,
+        });
+        app.update();
+        assert_eq!(
+            read_events::<UiNavFocusLostEvent>(&app),
+            vec![UiNavFocusLostEvent {
+                entity: buttons[0],
+                interaction_type: UiNavInteractionType::Manual,
+            }]
+        );
+        assert_eq!(
+            read_events::<UiNavFocusChangedEvent>(&app),
+            vec![UiNavFocusChangedEvent {
+                entity: buttons[1],
+                interaction_type: UiNavInteractionType::Manual,
+                previous: Some(buttons[0]),
+                previous_menu: Some(menus[0]),
+            }]
+        );
+    }
+
+    #[test]
+    fn menu_changes_are_sent_and_marked() {
+        let mut app = test_app();
+        let (menus, buttons) = spawn_menus(&mut app, 2, 1);
+        let buttons: Vec<Entity> = buttons.concat();
+        app.update();
+        app.update();
+        assert_eq!(
+            read_events::<UiNavMenuChangedEvent>(&app),
+            vec![UiNavMenuChangedEvent {
+                previous: None,
+                current: Some(menus[0]),
+                interaction_type: UiNavInteractionType::Auto,
+            }]
+        );
+        assert!(app.world().entity(menus[0]).contains::<CurrentNavMenu>());
+
+        // focusing a button in another menu changes the current menu
+        clear_events::<UiNavMenuChangedEvent>(&mut app);
+        app.world_mut().send_event(NavRequest::SetFocus {
+            entity: buttons[1],
+            interaction_type: UiNavInteractionType::Manual,
+        });
+        app.update();
+        assert_eq!(
+            read_events::<UiNavMenuChangedEvent>(&app),
+            vec![UiNavMenuChangedEvent {
+                previous: Some(menus[0]),
+                current: Some(menus[1]),
+                interaction_type: UiNavInteractionType::Manual,
+            }]
+        );
+        assert!(!app.world().entity(menus[0]).contains::<CurrentNavMenu>());
+        assert!(app.world().entity(menus[1]).contains::<CurrentNavMenu>());
+
+        // despawning the current menu clears it
+        clear_events::<UiNavMenuChangedEvent>(&mut app);
+        app.world_mut().entity_mut(menus[1]).despawn();
+        app.update();
+        assert_eq!(
+            read_events::<UiNavMenuChangedEvent>(&app).first(),
+            Some(&UiNavMenuChangedEvent {
+                previous: Some(menus[1]),
+                current: None,
+                interaction_type: UiNavInteractionType::Auto,
+            })
+        );
+    }
+
+    #[test]
+    fn locks_are_counted_by_reason() {
+        let mut app = test_app();
+        let (_, buttons) = spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        app.update();
+        app.update();
+
+        let reason = NavLockReason::Named("dialog");
+        for _ in 0..2 {
+            app.world_mut().send_event(NavRequest::LockWith {
+                reason,
+                scope: NavLockScope::MOVEMENT,
+            });
+        }
+        app.update();
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(focused(&mut app), Some(buttons[0]));
+
+        // a movement lock still allows clicks
+        clear_events::<UiNavClickEvent>(&mut app);
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert_eq!(
+            read_events::<UiNavClickEvent>(&app),
+            vec![UiNavClickEvent(buttons[0])]
+        );
+
+        // navigation stays locked until both locks are released
+        app.world_mut().send_event(NavRequest::UnlockWith(reason));
+        app.update();
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(focused(&mut app), Some(buttons[0]));
+
+        app.world_mut().send_event(NavRequest::UnlockWith(reason));
+        app.update();
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(focused(&mut app), Some(buttons[1]));
+    }
+
+    #[test]
+    fn locks_of_despawned_entities_are_released() {
+        let mut app = test_app();
+        let (_, buttons) = spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        app.update();
+        app.update();
+
+        let holder = app.world_mut().spawn_empty().id();
+        app.world_mut().send_event(NavRequest::LockWith {
+            reason: NavLockReason::Entity(holder),
+            scope: NavLockScope::ALL,
+        });
+        app.update();
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(focused(&mut app), Some(buttons[0]));
+
+        // the holder is despawned without unlocking
+        app.world_mut().entity_mut(holder).despawn();
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(focused(&mut app), Some(buttons[1]));
+        assert!(app.world().resource::<UiNavState>().locks.is_empty());
+    }
+
+    #[test]
+    fn focus_markers_follow_focus() {
+        let mut app = test_app();
+        let (menu, buttons) =
+            spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        // wrap each button in a row
+        let rows: Vec<Entity> = buttons
+            .iter()
+            .map(|button| {
+                let row = app.world_mut().spawn((ChildOf(menu), Node::default())).id();
+                app.world_mut().entity_mut(*button).insert(ChildOf(row));
+                row
+            })
+            .collect();
+        app.update();
+        app.update();
+        let world = app.world();
+        assert!(world.entity(buttons[0]).contains::<Focused>());
+        assert!(!world.entity(buttons[1]).contains::<Focused>());
+        assert!(world.entity(rows[0]).contains::<FocusWithin>());
+        assert!(!world.entity(rows[1]).contains::<FocusWithin>());
+        assert!(world.entity(menu).contains::<FocusWithin>());
+
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        let world = app.world();
+        assert!(!world.entity(buttons[0]).contains::<Focused>());
+        assert!(world.entity(buttons[1]).contains::<Focused>());
+        assert!(!world.entity(rows[0]).contains::<FocusWithin>());
+        assert!(world.entity(rows[1]).contains::<FocusWithin>());
+
+        send_key(&mut app, KeyCode::Enter, Key::Enter, ButtonState::Pressed);
+        assert!(app.world().entity(buttons[1]).contains::<NavPressed>());
+        send_key(&mut app, KeyCode::Enter, Key::Enter, ButtonState::Released);
+        assert!(!app.world().entity(buttons[1]).contains::<NavPressed>());
+
+        app.world_mut()
+            .get_mut::<Focusable>(buttons[0])
+            .unwrap()
+            .disable();
+        app.update();
+        assert!(app.world().entity(buttons[0]).contains::<NavDisabled>());
+    }
 }
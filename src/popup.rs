@@ -0,0 +1,101 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use crate::{components::*, events::*, types::UiNavInteractionType};
+
+/// Component for a popup menu that is opened from another entity, such as the list of a [`NavDropdown`].
+///
+/// A popup should be spawned as a root node alongside a prioritized, locked [`NavMenu`], which
+/// [`NavPopup::bundle`] provides. It is closed when:
+/// - The `Cancel` action is pressed while it is the current menu.
+/// - A mouse button is pressed outside of it.
+/// - Its `owner` is despawned.
+///
+/// When closed, the popup is despawned, focus is returned to its `owner`, and a [`UiNavPopupClosedEvent`] is sent.
+///
+/// [`NavDropdown`]: crate::prelude::NavDropdown
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+#[require(RelativeCursorPosition)]
+pub struct NavPopup {
+    /// The entity that opened the popup, which receives focus when it is closed
+    pub owner: Entity,
+}
+
+impl NavPopup {
+    pub fn new(owner: Entity) -> Self {
+        Self { owner }
+    }
+
+    /// Returns the components for a popup with its top-left corner at `position`, in logical pixels.
+    pub fn bundle(owner: Entity, position: Vec2) -> impl Bundle {
+        (
+            Self::new(owner),
+            NavMenu::default().prioritized().locked(),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            GlobalZIndex(i32::MAX),
+        )
+    }
+}
+
+/// Returns the logical rect of a UI node from its computed size and global transform.
+pub(crate) fn node_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    let center = transform.translation().truncate() * node.inverse_scale_factor();
+    Rect::from_center_size(center, node.size() * node.inverse_scale_factor())
+}
+
+/// Despawns a popup and returns focus to its owner.
+pub(crate) fn close_popup(
+    commands: &mut Commands,
+    nav_request_writer: &mut EventWriter<NavRequest>,
+    closed_writer: &mut EventWriter<UiNavPopupClosedEvent>,
+    entity: Entity,
+    popup: &NavPopup,
+) {
+    commands.entity(entity).try_despawn();
+    nav_request_writer.write(NavRequest::SetFocus {
+        entity: popup.owner,
+        interaction_type: UiNavInteractionType::Auto,
+    });
+    closed_writer.write(UiNavPopupClosedEvent {
+        popup: entity,
+        owner: popup.owner,
+    });
+}
+
+/// System that closes popups on cancel, when a mouse button is pressed outside of them, or when their owner is
+/// despawned.
+pub(crate) fn handle_popup_dismissal(
+    mut commands: Commands,
+    mut cancel_events: EventReader<UiNavCancelEvent>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    query: Query<(Entity, &NavPopup, &RelativeCursorPosition)>,
+    owner_query: Query<()>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+    mut closed_writer: EventWriter<UiNavPopupClosedEvent>,
+) {
+    let cancelled = cancel_events
+        .read()
+        .map(|event| event.0)
+        .collect::<Vec<_>>();
+    let is_mouse_pressed = mouse.get_just_pressed().next().is_some();
+    for (entity, popup, relative_cursor_position) in query.iter() {
+        if cancelled.contains(&entity)
+            || (is_mouse_pressed && !relative_cursor_position.mouse_over())
+            || !owner_query.contains(popup.owner)
+        {
+            close_popup(
+                &mut commands,
+                &mut nav_request_writer,
+                &mut closed_writer,
+                entity,
+                popup,
+            );
+        }
+    }
+}
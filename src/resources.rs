@@ -1,6 +1,9 @@
-use bevy::{prelude::*, time::Stopwatch};
+use bevy::{ecs::entity::Entities, platform::collections::HashSet, prelude::*, time::Stopwatch};
 
-use crate::types::UiNavDirection;
+use crate::{
+    input::ActionType,
+    types::{NavLock, NavLockReason, NavLockScope, UiNavDirection},
+};
 
 /// System set in which the UI navigation systems run.
 ///
@@ -11,7 +14,8 @@ pub struct UiNavSet;
 /// Resource holding the global menu navigation state.
 #[derive(Resource, Default, Debug)]
 pub(crate) struct UiNavState {
-    pub locked: bool,
+    /// The active navigation locks, in the order they were taken
+    pub locks: Vec<NavLock>,
     /// The current active `Menu`
     pub menu: Option<Entity>,
     /// The current direction being pressed
@@ -29,6 +33,45 @@ impl UiNavState {
         self.nav_timer.reset();
         self.hold_timer.reset();
     }
+
+    /// Returns the input blocked by all active locks.
+    pub fn lock_scope(&self) -> NavLockScope {
+        self.locks
+            .iter()
+            .fold(NavLockScope::default(), |scope, lock| {
+                scope.union(&lock.scope)
+            })
+    }
+
+    /// Adds a lock held by `reason`. Locks are counted, so a reason that locks twice must unlock twice, except for
+    /// [`NavLockReason::Default`] which is held at most once.
+    pub fn add_lock(&mut self, reason: NavLockReason, scope: NavLockScope) {
+        if reason == NavLockReason::Default && self.locks.iter().any(|lock| lock.reason == reason) {
+            return;
+        }
+        self.locks.push(NavLock { reason, scope });
+    }
+
+    /// Releases the last lock held by `reason`.
+    pub fn remove_lock(&mut self, reason: NavLockReason) {
+        if let Some(index) = self.locks.iter().rposition(|lock| lock.reason == reason) {
+            self.locks.remove(index);
+        }
+    }
+
+    /// Returns whether a lock is held by an entity that no longer exists.
+    pub fn has_stale_locks(&self, entities: &Entities) -> bool {
+        self.locks
+            .iter()
+            .any(|lock| matches!(lock.reason, NavLockReason::Entity(e) if !entities.contains(e)))
+    }
+
+    /// Releases the locks held by entities that no longer exist.
+    pub fn remove_stale_locks(&mut self, entities: &Entities) {
+        self.locks.retain(
+            |lock| !matches!(lock.reason, NavLockReason::Entity(e) if !entities.contains(e)),
+        );
+    }
 }
 
 /// Resource containing settings for how the UI Navigation plugin behaves.
@@ -44,6 +87,14 @@ pub struct UiNavSettings {
     /// `movement_speed_slow` when first pressed, and is `movement_speed_fast` when we have held it for the value of
     /// `movement_acceleration_time`.
     pub movement_acceleration_time: f32,
+    /// Whether inputs consumed by the UI should be cleared from `ButtonInput<KeyCode>` and `Gamepad` components, so
+    /// that systems scheduled after [`UiNavSet`] do not see them as just pressed or just released.
+    pub clear_consumed_input: bool,
+    /// Number of seconds a focusable must be hovered or focused before its `NavTooltip` is shown.
+    pub tooltip_delay: f32,
+    /// Whether a warning is logged whenever `validate_nav_graph` finds new issues in the navigation of the menus, such
+    /// as unreachable focusables. This validates the menus every frame, so it should only be enabled while debugging.
+    pub warn_invalid_nav_graphs: bool,
 }
 
 impl Default for UiNavSettings {
@@ -52,6 +103,51 @@ impl Default for UiNavSettings {
             movement_speed_slow: 0.5,
             movement_speed_fast: 0.1,
             movement_acceleration_time: 1.0,
+            clear_consumed_input: false,
+            tooltip_delay: 0.5,
+            warn_invalid_nav_graphs: false,
         }
     }
 }
+
+/// Resource recording which actions were consumed by the UI navigation plugin this frame.
+///
+/// An action is consumed when its input is pressed or just released while a menu has focus and navigation is not
+/// locked. While a [`NavTextInput`] is being edited, every pressed or just released key and the actions mapped to them
+/// are consumed. Systems reading this resource should be scheduled after [`UiNavSet`].
+///
+/// [`NavTextInput`]: crate::prelude::NavTextInput
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct UiNavConsumedInput {
+    pub(crate) actions: HashSet<ActionType>,
+    /// Keys consumed by a text input that is being edited
+    pub(crate) keys: HashSet<KeyCode>,
+}
+
+impl UiNavConsumedInput {
+    /// Returns whether `action` was consumed this frame.
+    pub fn consumed(&self, action: ActionType) -> bool {
+        self.actions.contains(&action)
+    }
+
+    /// Marks `action` as consumed for the rest of this frame.
+    pub fn consume(&mut self, action: ActionType) {
+        self.actions.insert(action);
+    }
+
+    /// Returns whether `key` was consumed this frame by a text input that is being edited.
+    pub fn key_consumed(&self, key: KeyCode) -> bool {
+        self.keys.contains(&key)
+    }
+
+    /// Returns whether no actions or keys were consumed this frame.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty() && self.keys.is_empty()
+    }
+
+    /// Iterates over the actions consumed this frame.
+    pub fn iter(&self) -> impl Iterator<Item = &ActionType> {
+        self.actions.iter()
+    }
+}
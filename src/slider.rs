@@ -0,0 +1,261 @@
+use bevy::{
+    ecs::{component::HookContext, world::DeferredWorld},
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
+
+use crate::{components::*, events::*, types::UiNavDirection};
+
+/// Component for a focusable slider.
+///
+/// While focused, `Left` and `Right` (or `Down` and `Up` when vertical) change the value by `step` instead of moving
+/// focus. Holding a direction repeats the change at the navigation hold speed configured in `UiNavSettings`. The value
+/// can also be dragged with the mouse.
+///
+/// A [`UiNavSliderChangedEvent`] is sent whenever the value is changed by the user.
+///
+/// The slider claims movement along its axis with a [`NavCaptureMovement`], which is set when the slider is inserted.
+/// Insert a new `NavSlider` to change its orientation.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(Focusable, NavCaptureMovement = NavCaptureMovement::HORIZONTAL)]
+#[component(on_insert = set_slider_capture)]
+pub struct NavSlider {
+    pub min: f32,
+    pub max: f32,
+    /// The amount the value changes by per movement. Dragged values are snapped to this step. No snapping is applied
+    /// if it is zero.
+    pub step: f32,
+    pub value: f32,
+    /// Whether the slider changes with `Up` and `Down` instead of `Left` and `Right`
+    pub(crate) is_vertical: bool,
+}
+
+impl Default for NavSlider {
+    fn default() -> Self {
+        Self {
+            min: 0.,
+            max: 1.,
+            step: 0.1,
+            value: 0.,
+            is_vertical: false,
+        }
+    }
+}
+
+impl NavSlider {
+    pub fn new(min: f32, max: f32, step: f32, value: f32) -> Self {
+        Self {
+            min,
+            max,
+            step,
+            value: value.clamp(min, max),
+            is_vertical: false,
+        }
+    }
+
+    /// Sets the `is_vertical` value to `true` and returns the `NavSlider`.
+    pub fn vertical(mut self) -> Self {
+        self.is_vertical = true;
+        self
+    }
+
+    /// Returns whether the slider changes with `Up` and `Down` instead of `Left` and `Right`.
+    pub fn is_vertical(&self) -> bool {
+        self.is_vertical
+    }
+
+    /// Returns the value as a fraction between `min` and `max`, which is useful for sizing a fill bar.
+    pub fn fraction(&self) -> f32 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0., 1.)
+        } else {
+            0.
+        }
+    }
+
+    /// Sets the value, clamped between `min` and `max` and snapped to `step`.
+    pub fn set_value(&mut self, value: f32) {
+        let value = if self.step > 0. {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        self.value = value.clamp(self.min, self.max);
+    }
+
+    /// Sets the value from a fraction between `min` and `max`.
+    pub fn set_fraction(&mut self, fraction: f32) {
+        self.set_value(self.min + (self.max - self.min) * fraction.clamp(0., 1.));
+    }
+
+    /// Returns the number of steps a movement in `direction` changes the value by.
+    fn steps_for(&self, direction: UiNavDirection) -> f32 {
+        match (self.is_vertical, direction) {
+            (false, UiNavDirection::Right) | (true, UiNavDirection::Up) => 1.,
+            (false, UiNavDirection::Left) | (true, UiNavDirection::Down) => -1.,
+            _ => 0.,
+        }
+    }
+}
+
+/// Hook that makes an inserted slider claim movement along its axis.
+fn set_slider_capture(mut world: DeferredWorld, context: HookContext) {
+    let Some(is_vertical) = world
+        .get::<NavSlider>(context.entity)
+        .map(|slider| slider.is_vertical)
+    else {
+        return;
+    };
+    let new_capture = if is_vertical {
+        NavCaptureMovement::VERTICAL
+    } else {
+        NavCaptureMovement::HORIZONTAL
+    };
+    if let Some(mut capture) = world.get_mut::<NavCaptureMovement>(context.entity) {
+        capture.set_if_neq(new_capture);
+    }
+}
+
+/// System that changes slider values when they claim movement.
+pub(crate) fn handle_slider_movement(
+    mut events: EventReader<UiNavCapturedMovementEvent>,
+    mut query: Query<&mut NavSlider>,
+    mut changed_writer: EventWriter<UiNavSliderChangedEvent>,
+) {
+    for event in events.read() {
+        if let Ok(mut slider) = query.get_mut(event.entity) {
+            let steps = slider.steps_for(event.direction);
+            let value = (slider.value + slider.step * steps).clamp(slider.min, slider.max);
+            if value != slider.value {
+                slider.value = value;
+                changed_writer.write(UiNavSliderChangedEvent {
+                    entity: event.entity,
+                    value,
+                });
+            }
+        }
+    }
+}
+
+/// System that sets slider values while they are dragged with the mouse.
+pub(crate) fn handle_slider_drag(
+    mut query: Query<(Entity, &mut NavSlider, &Focusable, &RelativeCursorPosition)>,
+    mut changed_writer: EventWriter<UiNavSliderChangedEvent>,
+) {
+    for (entity, mut slider, focusable, relative_cursor_position) in query.iter_mut() {
+        // only drag when the press started on this slider
+        if !focusable.is_pressed()
+            || !focusable.is_pressed_interaction
+            || !focusable.is_pressed_interaction_from_active
+        {
+            continue;
+        }
+        let Some(position) = relative_cursor_position.normalized else {
+            continue;
+        };
+
+        // (0, 0) is the top-left corner of the node
+        let fraction = if slider.is_vertical {
+            1. - position.y
+        } else {
+            position.x
+        };
+        let previous_value = slider.value;
+        slider.bypass_change_detection().set_fraction(fraction);
+        if slider.value != previous_value {
+            slider.set_changed();
+            changed_writer.write(UiNavSliderChangedEvent {
+                entity,
+                value: slider.value,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::{prelude::NavMenu, test_utils::*};
+
+    #[test]
+    fn set_value_snaps_and_clamps() {
+        let mut slider = NavSlider::new(0., 10., 2., 0.);
+        slider.set_value(4.9);
+        assert_relative_eq!(slider.value, 4.);
+        slider.set_value(12.);
+        assert_relative_eq!(slider.value, 10.);
+        slider.set_fraction(0.5);
+        assert_relative_eq!(slider.value, 6.);
+        assert_relative_eq!(slider.fraction(), 0.6);
+    }
+
+    #[test]
+    fn slider_captures_movement_when_spawned() {
+        let mut app = test_app();
+        let capture =
+            |app: &App, entity: Entity| *app.world().get::<NavCaptureMovement>(entity).unwrap();
+        let slider = app.world_mut().spawn(NavSlider::default()).id();
+        assert_eq!(capture(&app, slider), NavCaptureMovement::HORIZONTAL);
+        let vertical = app.world_mut().spawn(NavSlider::default().vertical()).id();
+        assert_eq!(capture(&app, vertical), NavCaptureMovement::VERTICAL);
+
+        // inserting a slider with another orientation changes the capture
+        app.world_mut()
+            .entity_mut(vertical)
+            .insert(NavSlider::default());
+        assert_eq!(capture(&app, vertical), NavCaptureMovement::HORIZONTAL);
+    }
+
+    #[test]
+    fn focused_slider_claims_horizontal_movement() {
+        let mut app = test_app();
+        let mut slider = Entity::PLACEHOLDER;
+        let mut button = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(NavMenu::default().prioritized())
+            .with_children(|p| {
+                slider = p
+                    .spawn((
+                        NavSlider::new(0., 1., 0.25, 0.5),
+                        Focusable::prioritized(),
+                        focus_node(Vec2::ZERO, Vec2::new(100., 20.)),
+                    ))
+                    .id();
+                button = p
+                    .spawn((
+                        Focusable::default(),
+                        focus_node(Vec2::new(200., 0.), Vec2::new(100., 20.)),
+                    ))
+                    .id();
+            });
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), Some(slider));
+
+        tap_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+        assert_eq!(focused(&mut app), Some(slider));
+        assert_relative_eq!(app.world().get::<NavSlider>(slider).unwrap().value, 0.75);
+        assert_eq!(
+            read_events::<UiNavSliderChangedEvent>(&app),
+            vec![UiNavSliderChangedEvent {
+                entity: slider,
+                value: 0.75
+            }]
+        );
+
+        // vertical movement is not claimed
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        tap_key(&mut app, KeyCode::ArrowLeft, Key::ArrowLeft);
+        assert_relative_eq!(app.world().get::<NavSlider>(slider).unwrap().value, 0.5);
+
+        // without the capture, movement changes focus
+        app.world_mut()
+            .entity_mut(slider)
+            .insert(NavCaptureMovement::VERTICAL);
+        tap_key(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+        assert_eq!(focused(&mut app), Some(button));
+    }
+}
@@ -2,7 +2,8 @@ use bevy::{platform::collections::HashMap, prelude::*};
 
 use crate::{
     focus_node::{FocusNode, FocusTarget},
-    prelude::{Focusable, NavMenu, UiNavDirection, UiNavInteractionType, UiNavState},
+    movement_trace::NavMovementTrace,
+    prelude::{Focusable, NavLockScope, NavMenu, UiNavDirection, UiNavInteractionType, UiNavState},
     utils::f32_equal,
 };
 
@@ -19,6 +20,7 @@ pub struct UiSpatialMap {
     focusables: HashMap<Entity, FocusNode>,
     mouse_only_focusables: HashMap<Entity, FocusNode>,
     current_menu: Option<Entity>,
+    current_menu_interaction_type: Option<UiNavInteractionType>,
 
     // current focusable
     current_focusable: Option<Entity>,
@@ -34,9 +36,8 @@ pub struct UiSpatialMap {
     _original_menu: Option<Entity>,
     _original_mouse_focusable: Option<Entity>,
 
-    // locked
-    locked: bool,
-    _original_locked: bool,
+    // the input blocked by navigation locks
+    lock_scope: NavLockScope,
 }
 
 impl UiSpatialMap {
@@ -103,17 +104,17 @@ impl UiSpatialMap {
             current_interaction_type: None,
             is_current_pressed,
             current_menu: nav_state.menu,
+            current_menu_interaction_type: None,
             _original_focusable: current_focusable,
             _original_menu: nav_state.menu,
             _events: vec![],
             current_mouse_focusable,
             _original_mouse_focusable: current_mouse_focusable,
-            locked: nav_state.locked,
-            _original_locked: nav_state.locked,
+            lock_scope: nav_state.lock_scope(),
         };
 
         // If there is no current menu then attempt to set the next menu
-        if !ui_spatial_map.locked && ui_spatial_map.current_menu.is_none() {
+        if !ui_spatial_map.lock_scope.movement && ui_spatial_map.current_menu.is_none() {
             if let Some((menu_entity, _)) = ui_spatial_map
                 .menus
                 .iter()
@@ -126,12 +127,12 @@ impl UiSpatialMap {
                     }
                 })
             {
-                ui_spatial_map.set_focus_to_menu(Some(*menu_entity));
+                ui_spatial_map.set_focus_to_menu(Some(*menu_entity), UiNavInteractionType::Auto);
             }
         }
 
         // If there is no current focusable then attempt to find the next focusable
-        if !ui_spatial_map.locked
+        if !ui_spatial_map.lock_scope.movement
             && ui_spatial_map.current_menu.is_some()
             && ui_spatial_map.current_focusable.is_none()
         {
@@ -154,14 +155,14 @@ impl UiSpatialMap {
         ) {
             self.set_focus_to_focusable(Some(entity), interaction_type);
             if new_menu != self.current_menu {
-                self.set_focus_to_menu(new_menu);
+                self.set_focus_to_menu(new_menu, interaction_type);
             }
         } else if self.mouse_only_focusables.contains_key(&entity)
             && Some(entity) != self.current_mouse_focusable
         {
             self.set_focus_to_mouse_only_focusable(Some(entity));
         } else if self.menus.contains_key(&entity) && Some(entity) != self.current_menu {
-            self.set_focus_to_menu(Some(entity));
+            self.set_focus_to_menu(Some(entity), interaction_type);
             self.focus_on_node_in_current_menu();
         }
     }
@@ -182,37 +183,58 @@ impl UiSpatialMap {
         self.is_current_pressed = false;
     }
 
-    fn set_focus_to_menu(&mut self, menu: Option<Entity>) {
+    fn set_focus_to_menu(&mut self, menu: Option<Entity>, interaction_type: UiNavInteractionType) {
         self.cancel_press();
         self.current_menu = menu;
+        self.current_menu_interaction_type = Some(interaction_type);
     }
 
     fn focus_on_node_in_current_menu(&mut self) {
         // change focusable to another in this menu
+        let focusable = self.initial_focusable(self.current_menu);
+        self.set_focus_to_focusable(focusable, UiNavInteractionType::Auto);
+    }
+
+    /// Returns the focusable that receives focus when a menu becomes the current menu, which is its priority focusable
+    /// if found, otherwise the first focusable in the menu.
+    pub fn initial_focusable(&self, menu: Option<Entity>) -> Option<Entity> {
         let priority_focusable = self
             .focusables
             .iter()
-            .find(|(_, focus_node)| focus_node.menu == self.current_menu && focus_node.is_priority)
+            .find(|(_, focus_node)| focus_node.menu == menu && focus_node.is_priority)
             .map(|(entity, _)| *entity);
 
-        // use the priority focusable if found, otherwise the first focusable in the menu
-        let focusable = priority_focusable.or_else(|| {
+        priority_focusable.or_else(|| {
             self.focusables
                 .iter()
-                .find(|(_, focus_node)| focus_node.menu == self.current_menu)
+                .find(|(_, focus_node)| focus_node.menu == menu)
                 .map(|(entity, _)| *entity)
-        });
+        })
+    }
 
-        self.set_focus_to_focusable(focusable, UiNavInteractionType::Auto);
+    /// Iterates over the focusables that can receive focus, excluding mouse-only focusables.
+    pub fn focusables(&self) -> impl Iterator<Item = (Entity, &FocusNode)> {
+        self.focusables
+            .iter()
+            .map(|(entity, focus_node)| (*entity, focus_node))
+    }
+
+    pub fn can_move(&self) -> bool {
+        !self.lock_scope.movement && self.current_focusable.is_some() && !self.is_current_pressed
     }
 
-    fn can_move(&self) -> bool {
-        !self.locked && self.current_focusable.is_some() && !self.is_current_pressed
+    /// Returns whether the action and context menu requests can be applied to the current focusable.
+    pub fn can_act(&self) -> bool {
+        !self.lock_scope.actions && self.current_focusable.is_some() && !self.is_current_pressed
     }
 
-    pub fn get_new_menu(&self) -> Option<Option<Entity>> {
+    pub fn get_new_menu(&self) -> Option<(Option<Entity>, UiNavInteractionType)> {
         if self.current_menu != self._original_menu {
-            Some(self.current_menu)
+            Some((
+                self.current_menu,
+                self.current_menu_interaction_type
+                    .unwrap_or(UiNavInteractionType::Auto),
+            ))
         } else {
             None
         }
@@ -222,6 +244,10 @@ impl UiSpatialMap {
         self.current_menu
     }
 
+    pub fn focusable(&self) -> Option<Entity> {
+        self.current_focusable
+    }
+
     pub fn get_new_focusable(&self) -> Option<(Option<Entity>, UiNavInteractionType)> {
         if self.current_focusable != self._original_focusable {
             Some((
@@ -242,16 +268,8 @@ impl UiSpatialMap {
         }
     }
 
-    pub fn get_new_locked(&self) -> Option<bool> {
-        if self.locked != self._original_locked {
-            Some(self.locked)
-        } else {
-            None
-        }
-    }
-
     pub fn press(&mut self) -> Option<Entity> {
-        if self.locked {
+        if self.lock_scope.actions {
             return None;
         }
         // ignore if we are currently pressing a button, or there is no current focusable
@@ -267,7 +285,7 @@ impl UiSpatialMap {
     }
 
     pub fn release(&mut self) -> Option<Entity> {
-        if self.locked {
+        if self.lock_scope.actions {
             return None;
         }
         // ignore if we are currently pressing a button, or there is no current focusable
@@ -293,36 +311,43 @@ impl UiSpatialMap {
         }
     }
 
-    /// Lock UI navigation
-    pub fn lock(&mut self) {
-        self.locked = true;
-    }
-
-    /// Unlock UI navigation
-    pub fn unlock(&mut self) {
-        self.locked = false;
+    /// Sets the input blocked by navigation locks
+    pub fn set_lock_scope(&mut self, lock_scope: NavLockScope) {
+        self.lock_scope = lock_scope;
     }
 
-    pub fn apply_movement(&mut self, direction: UiNavDirection) {
+    /// Moves focus from the current focusable in `direction`, and returns the trace of the decision if focus could move.
+    pub fn apply_movement(&mut self, direction: UiNavDirection) -> Option<NavMovementTrace> {
         if !self.can_move() {
-            return;
+            return None;
         }
 
-        let current_entity = self.current_focusable.unwrap();
-        let current = self.focusables.get(&current_entity).unwrap();
+        let trace = self.trace_movement(self.current_focusable?, direction)?;
+        if let Some(target) = trace.target() {
+            self.current_focusable = Some(target);
+            self.current_interaction_type = Some(UiNavInteractionType::Button);
+        }
+        Some(trace)
+    }
 
-        let is_current_menu_wrap = self
-            .current_menu
+    /// Returns the trace of a movement from the `origin` focusable in `direction` without applying it, or `None` if
+    /// the origin is not a focusable that can receive focus.
+    pub fn trace_movement(
+        &self,
+        origin: Entity,
+        direction: UiNavDirection,
+    ) -> Option<NavMovementTrace> {
+        let current = self.focusables.get(&origin)?;
+
+        let is_current_menu_wrap = current
+            .menu
             .and_then(|menu_entity| self.menus.get(&menu_entity))
             .is_some_and(|menu| menu.is_wrap);
 
-        // find the nearest, and furthest nodes in the direction of travel
-        let (nearest, furthest) = self
+        let candidates: Vec<FocusTarget> = self
             .focusables
             .iter()
-            .filter(|(entity, focus_node)| {
-                **entity != current_entity && focus_node.menu == self.current_menu
-            })
+            .filter(|(entity, focus_node)| **entity != origin && focus_node.menu == current.menu)
             // map to a `FocusTarget` type
             .map(|(entity, focus_node)| {
                 let distance = current.distance_to(focus_node);
@@ -345,6 +370,11 @@ impl UiSpatialMap {
                     distance,
                 }
             })
+            .collect();
+
+        // find the nearest, and furthest nodes in the direction of travel
+        let (nearest, furthest) = candidates
+            .iter()
             // Remove any nodes that do not lie along the axis of the movement event. If wrapping is enabled,
             // allow any nodes along the axis. Otherwise, only allow nodes in the direction of the movement event.
             .filter(|focus_target| {
@@ -357,7 +387,7 @@ impl UiSpatialMap {
             .fold(
                 (None, None),
                 #[allow(clippy::type_complexity)]
-                |(acc_nearest, acc_furthest), e| -> (Option<FocusTarget>, Option<FocusTarget>) {
+                |(acc_nearest, acc_furthest), e| -> (Option<&FocusTarget>, Option<&FocusTarget>) {
                     let e_is_in_direction = e.is_in_direction;
 
                     // Fold the nearest focus node in the direction of the movement event
@@ -369,13 +399,13 @@ impl UiSpatialMap {
                                     || e.distance.total < acc_nearest.distance.total))
                                 || (!acc_nearest.is_prefer && e.is_prefer))
                         {
-                            Some(e.clone())
+                            Some(e)
                         } else {
                             Some(acc_nearest)
                         }
                     } else if e_is_in_direction {
                         // set the initial nearest node
-                        Some(e.clone())
+                        Some(e)
                     } else {
                         None
                     };
@@ -396,13 +426,13 @@ impl UiSpatialMap {
                                         && e.position.x < acc_furthest.position.x)))
                                 || (!acc_furthest.is_prefer && e.is_prefer))
                         {
-                            Some(e.clone())
+                            Some(e)
                         } else {
                             Some(acc_furthest)
                         }
                     } else if !e_is_in_direction {
                         // set the initial furthest node if it does not lie in the direction of the movement event
-                        Some(e.clone())
+                        Some(e)
                     } else {
                         None
                     };
@@ -411,14 +441,20 @@ impl UiSpatialMap {
                 },
             );
 
-        if let Some(nearest) = nearest {
-            self.current_focusable = Some(nearest.entity);
-            self.current_interaction_type = Some(UiNavInteractionType::Button);
-        } else if let (Some(furthest), true) = (furthest, is_current_menu_wrap) {
-            // No nearest, wrapping around
-            self.current_focusable = Some(furthest.entity);
-            self.current_interaction_type = Some(UiNavInteractionType::Button);
-        }
+        let (nearest, furthest) = (
+            nearest.map(|target| target.entity),
+            furthest.map(|target| target.entity),
+        );
+        Some(NavMovementTrace {
+            direction,
+            origin,
+            origin_node: current.clone(),
+            menu: current.menu,
+            is_wrap: is_current_menu_wrap,
+            candidates,
+            nearest,
+            furthest,
+        })
     }
 }
 
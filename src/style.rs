@@ -0,0 +1,366 @@
+use bevy::prelude::*;
+
+use crate::{components::*, types::*};
+
+/// Style values applied to a focusable while it is in a state. Values that are `None` are not changed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub struct NavStyleValues {
+    pub background_color: Option<Color>,
+    pub border_color: Option<Color>,
+    pub outline: Option<Outline>,
+    /// The color of the `TextColor` on the focusable and its descendants
+    pub text_color: Option<Color>,
+    /// The scale of the node's `Transform`
+    pub scale: Option<f32>,
+}
+
+impl NavStyleValues {
+    pub fn with_background_color(mut self, color: impl Into<Color>) -> Self {
+        self.background_color = Some(color.into());
+        self
+    }
+
+    pub fn with_border_color(mut self, color: impl Into<Color>) -> Self {
+        self.border_color = Some(color.into());
+        self
+    }
+
+    pub fn with_outline(mut self, outline: Outline) -> Self {
+        self.outline = Some(outline);
+        self
+    }
+
+    pub fn with_text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Returns these values with any values set in `other` replacing them.
+    pub fn merged(&self, other: &Self) -> Self {
+        Self {
+            background_color: other.background_color.or(self.background_color),
+            border_color: other.border_color.or(self.border_color),
+            outline: other.outline.or(self.outline),
+            text_color: other.text_color.or(self.text_color),
+            scale: other.scale.or(self.scale),
+        }
+    }
+
+    /// Interpolates from these values to `other`. Values that are only set in `other` are not interpolated, and the
+    /// width and offset of an outline are not interpolated.
+    fn mix(&self, other: &Self, t: f32) -> Self {
+        fn mix_color(a: Option<Color>, b: Option<Color>, t: f32) -> Option<Color> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a.mix(&b, t)),
+                (_, b) => b,
+            }
+        }
+        Self {
+            background_color: mix_color(self.background_color, other.background_color, t),
+            border_color: mix_color(self.border_color, other.border_color, t),
+            outline: other.outline.map(|outline| Outline {
+                color: mix_color(self.outline.map(|o| o.color), Some(outline.color), t)
+                    .unwrap_or(outline.color),
+                ..outline
+            }),
+            text_color: mix_color(self.text_color, other.text_color, t),
+            scale: match (self.scale, other.scale) {
+                (Some(a), Some(b)) => Some(a.lerp(b, t)),
+                (_, b) => b,
+            },
+        }
+    }
+}
+
+/// An eased transition between the styles of a [`NavStyle`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Debug, PartialEq)]
+pub struct NavStyleTransition {
+    /// The duration of the transition in seconds
+    pub duration: f32,
+    pub ease: EaseFunction,
+}
+
+impl NavStyleTransition {
+    pub fn new(duration: f32, ease: EaseFunction) -> Self {
+        Self { duration, ease }
+    }
+}
+
+/// Component that styles a focusable depending on its [`FocusState`], replacing a hand-written `button_style` system.
+///
+/// The values of the current state are merged over the `none` values, and the `hovered` values are merged over them
+/// while the focusable is hovered by the mouse. They are applied after [`UiNavSet`] to the entity's `BackgroundColor`,
+/// `BorderColor`, `Outline` and `Transform` scale, which are inserted if missing, and to the `TextColor` of the entity
+/// and its descendants.
+///
+/// With a `transition`, colors and scale are eased from their current values whenever the state changes. Transitions
+/// use real time, so that they still run while virtual time is paused.
+///
+/// [`UiNavSet`]: crate::prelude::UiNavSet
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(NavStyleState)]
+pub struct NavStyle {
+    /// Values while not focused, which the other states are merged over
+    pub none: NavStyleValues,
+    /// Values while focused
+    pub focus: NavStyleValues,
+    /// Values while focused and pressed
+    pub focus_press: NavStyleValues,
+    /// Values while disabled
+    pub disabled: NavStyleValues,
+    /// Values while hovered by the mouse, merged over the values of the current state
+    pub hovered: NavStyleValues,
+    pub transition: Option<NavStyleTransition>,
+}
+
+impl NavStyle {
+    pub fn new(none: NavStyleValues) -> Self {
+        Self { none, ..default() }
+    }
+
+    pub fn with_focus(mut self, values: NavStyleValues) -> Self {
+        self.focus = values;
+        self
+    }
+
+    pub fn with_focus_press(mut self, values: NavStyleValues) -> Self {
+        self.focus_press = values;
+        self
+    }
+
+    pub fn with_disabled(mut self, values: NavStyleValues) -> Self {
+        self.disabled = values;
+        self
+    }
+
+    pub fn with_hovered(mut self, values: NavStyleValues) -> Self {
+        self.hovered = values;
+        self
+    }
+
+    pub fn with_transition(mut self, duration: f32, ease: EaseFunction) -> Self {
+        self.transition = Some(NavStyleTransition::new(duration, ease));
+        self
+    }
+
+    /// Returns the values to apply to a focusable in its current state.
+    pub fn values(&self, focusable: &Focusable) -> NavStyleValues {
+        let values = match focusable.state() {
+            FocusState::None => self.none,
+            FocusState::Focus => self.none.merged(&self.focus),
+            FocusState::FocusPress => self.none.merged(&self.focus).merged(&self.focus_press),
+            FocusState::Disabled => self.none.merged(&self.disabled),
+        };
+        if focusable.is_hovered() {
+            values.merged(&self.hovered)
+        } else {
+            values
+        }
+    }
+}
+
+/// Component tracking the transition of a [`NavStyle`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub(crate) struct NavStyleState {
+    from: NavStyleValues,
+    to: NavStyleValues,
+    elapsed: f32,
+    is_initialized: bool,
+}
+
+impl NavStyleState {
+    /// Returns the values at the current point of the transition.
+    fn current(&self, transition: Option<&NavStyleTransition>) -> NavStyleValues {
+        match transition {
+            Some(transition) if self.elapsed < transition.duration => {
+                let t = transition
+                    .ease
+                    .sample_clamped(self.elapsed / transition.duration);
+                self.from.mix(&self.to, t)
+            }
+            _ => self.to,
+        }
+    }
+}
+
+/// System that starts a transition to the new style values when a focusable or its [`NavStyle`] changes.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_nav_style_targets(
+    mut query: Query<
+        (&Focusable, &NavStyle, &mut NavStyleState),
+        Or<(Changed<Focusable>, Changed<NavStyle>)>,
+    >,
+) {
+    for (focusable, style, mut state) in query.iter_mut() {
+        let target = style.values(focusable);
+        if state.is_initialized && state.to == target {
+            continue;
+        }
+        let from = state.current(style.transition.as_ref());
+        *state = NavStyleState {
+            from: if state.is_initialized { from } else { target },
+            to: target,
+            elapsed: 0.,
+            is_initialized: true,
+        };
+    }
+}
+
+/// System that applies style values to focusables while their [`NavStyleState`] changes, and advances transitions.
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_nav_styles(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut query: Query<
+        (
+            Entity,
+            &NavStyle,
+            &mut NavStyleState,
+            Option<&mut BackgroundColor>,
+            Option<&mut BorderColor>,
+            Option<&mut Outline>,
+            Option<&mut Transform>,
+        ),
+        Changed<NavStyleState>,
+    >,
+    children_query: Query<&Children>,
+    mut text_color_query: Query<&mut TextColor>,
+) {
+    for (entity, style, mut state, background, border, outline, transform) in query.iter_mut() {
+        let values = state.current(style.transition.as_ref());
+
+        if let Some(color) = values.background_color {
+            match background {
+                Some(mut background) => background.set_if_neq(BackgroundColor(color)),
+                None => {
+                    commands.entity(entity).try_insert(BackgroundColor(color));
+                    true
+                }
+            };
+        }
+        if let Some(color) = values.border_color {
+            match border {
+                Some(mut border) => border.set_if_neq(BorderColor(color)),
+                None => {
+                    commands.entity(entity).try_insert(BorderColor(color));
+                    true
+                }
+            };
+        }
+        if let Some(new_outline) = values.outline {
+            match outline {
+                Some(mut outline) => outline.set_if_neq(new_outline),
+                None => {
+                    commands.entity(entity).try_insert(new_outline);
+                    true
+                }
+            };
+        }
+        if let Some(scale) = values.scale {
+            let scale = Vec3::new(scale, scale, 1.);
+            match transform {
+                Some(mut transform) => {
+                    if transform.scale != scale {
+                        transform.scale = scale;
+                    }
+                }
+                None => {
+                    commands
+                        .entity(entity)
+                        .try_insert(Transform::from_scale(scale));
+                }
+            }
+        }
+        if let Some(color) = values.text_color {
+            let mut iter = text_color_query.iter_many_mut(
+                std::iter::once(entity).chain(children_query.iter_descendants(entity)),
+            );
+            while let Some(mut text_color) = iter.fetch_next() {
+                text_color.set_if_neq(TextColor(color));
+            }
+        }
+
+        // advance the transition, which keeps the state changed until it has finished
+        if let Some(transition) = style.transition {
+            if state.elapsed < transition.duration {
+                state.elapsed += time.delta_secs();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{color::palettes::css, input::keyboard::Key};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    fn style() -> NavStyle {
+        NavStyle::new(
+            NavStyleValues::default()
+                .with_background_color(css::BLACK)
+                .with_text_color(css::WHITE),
+        )
+        .with_focus(NavStyleValues::default().with_background_color(css::RED))
+        .with_focus_press(NavStyleValues::default().with_scale(0.9))
+        .with_hovered(NavStyleValues::default().with_border_color(css::WHITE))
+    }
+
+    #[test]
+    fn values_are_merged_over_the_none_state() {
+        let style = style();
+        let mut focusable = Focusable::default();
+        assert_eq!(style.values(&focusable), style.none);
+
+        focusable.is_focused = true;
+        focusable.is_hovered_interaction = true;
+        assert_eq!(
+            style.values(&focusable),
+            NavStyleValues::default()
+                .with_background_color(css::RED)
+                .with_text_color(css::WHITE)
+                .with_border_color(css::WHITE)
+        );
+
+        focusable.is_pressed_key = true;
+        assert_eq!(style.values(&focusable).scale, Some(0.9));
+    }
+
+    #[test]
+    fn styles_are_applied_when_focus_changes() {
+        let mut app = test_app();
+        let (_, buttons) = spawn_column(&mut app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        for button in buttons.iter() {
+            app.world_mut().entity_mut(*button).insert(style());
+        }
+        let text = app
+            .world_mut()
+            .spawn((ChildOf(buttons[1]), TextColor::default()))
+            .id();
+        app.update();
+        app.update();
+
+        let background =
+            |app: &App, entity: Entity| app.world().get::<BackgroundColor>(entity).unwrap().0;
+        assert_eq!(background(&app, buttons[0]), css::RED.into());
+        assert_eq!(background(&app, buttons[1]), css::BLACK.into());
+        assert_eq!(
+            app.world().get::<TextColor>(text).unwrap().0,
+            css::WHITE.into()
+        );
+
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(background(&app, buttons[0]), css::BLACK.into());
+        assert_eq!(background(&app, buttons[1]), css::RED.into());
+    }
+}
@@ -0,0 +1,382 @@
+use bevy::{
+    ecs::query::{QueryData, QueryFilter},
+    prelude::*,
+};
+
+use crate::{components::*, events::*, resources::UiNavState, types::UiNavInteractionType};
+
+/// Component for a container of [`NavTab`] focusables, each of which shows a content panel when selected.
+///
+/// Tabs belong to their nearest `NavTabs` ancestor, and are ordered by their position in the hierarchy. The panels of
+/// tabs that are not selected are hidden by setting their `Node::display` to `Display::None`, and the selected panel
+/// is shown with `Display::Flex`.
+///
+/// The `NextTab` and `PreviousTab` actions select the next or previous tab wherever focus is, and move focus into the
+/// first [`NavMenu`] of the newly shown panel. When there are multiple `NavTabs`, the one containing the current menu
+/// is used, otherwise the first one found. Tab actions are ignored while navigation is locked, or while the current
+/// menu is a locked menu outside of the `NavTabs`, such as a modal or popup.
+///
+/// Clicking a tab also selects it, without moving focus.
+///
+/// A [`UiNavTabChangedEvent`] is sent whenever the selected tab changes.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct NavTabs {
+    pub(crate) selected: usize,
+    /// Whether selecting the next tab after the last one selects the first one, and vice versa.
+    pub is_wrap: bool,
+}
+
+impl NavTabs {
+    /// Creates a `NavTabs` with the tab at `selected` initially selected.
+    pub fn new(selected: usize) -> Self {
+        Self {
+            selected,
+            is_wrap: false,
+        }
+    }
+
+    /// Sets the `is_wrap` value and returns the `NavTabs`.
+    pub fn with_wrap(mut self, is_wrap: bool) -> Self {
+        self.is_wrap = is_wrap;
+        self
+    }
+
+    /// Returns the index of the selected tab.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects a tab by index. No event is sent and focus is not moved.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index;
+    }
+}
+
+/// Component for a focusable tab within a [`NavTabs`] container.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+#[require(Focusable)]
+pub struct NavTab {
+    /// The content panel shown while this tab is selected
+    pub panel: Entity,
+    pub(crate) is_selected: bool,
+}
+
+impl NavTab {
+    pub fn new(panel: Entity) -> Self {
+        Self {
+            panel,
+            is_selected: false,
+        }
+    }
+
+    /// Returns whether this is the selected tab.
+    pub fn is_selected(&self) -> bool {
+        self.is_selected
+    }
+}
+
+/// Returns the nearest [`NavTabs`] ancestor of an entity.
+fn find_tabs<D: QueryData, F: QueryFilter>(
+    entity: Entity,
+    parent_query: &Query<&ChildOf>,
+    tabs_query: &Query<D, F>,
+) -> Option<Entity> {
+    parent_query
+        .iter_ancestors(entity)
+        .find(|e| tabs_query.contains(*e))
+}
+
+/// Returns the tabs of a [`NavTabs`] in hierarchy order.
+fn tabs_in_order<D: QueryData, F: QueryFilter>(
+    tabs_entity: Entity,
+    tab_query: &Query<&NavTab>,
+    parent_query: &Query<&ChildOf>,
+    children_query: &Query<&Children>,
+    tabs_query: &Query<D, F>,
+) -> Vec<Entity> {
+    children_query
+        .iter_descendants(tabs_entity)
+        .filter(|e| {
+            tab_query.contains(*e) && find_tabs(*e, parent_query, tabs_query) == Some(tabs_entity)
+        })
+        .collect()
+}
+
+/// System that selects tabs in response to tab requests and clicks.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handle_tab_requests(
+    mut requests: ParamSet<(EventReader<NavRequest>, EventWriter<NavRequest>)>,
+    mut click_events: EventReader<UiNavClickEvent>,
+    mut tabs_query: Query<(Entity, &mut NavTabs)>,
+    tab_query: Query<&NavTab>,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    menu_query: Query<(Entity, &NavMenu)>,
+    nav_state: Res<UiNavState>,
+    mut changed_writer: EventWriter<UiNavTabChangedEvent>,
+) {
+    // (tabs entity, tab index, whether to move focus into the panel)
+    let mut selections: Vec<(Entity, usize, bool)> = Vec::new();
+
+    // select clicked tabs
+    for event in click_events.read() {
+        if tab_query.contains(event.0) {
+            if let Some(tabs_entity) = find_tabs(event.0, &parent_query, &tabs_query) {
+                let tabs = tabs_in_order(
+                    tabs_entity,
+                    &tab_query,
+                    &parent_query,
+                    &children_query,
+                    &tabs_query,
+                );
+                if let Some(index) = tabs.iter().position(|e| *e == event.0) {
+                    selections.push((tabs_entity, index, false));
+                }
+            }
+        }
+    }
+
+    // select the next or previous tab
+    let steps = requests
+        .p0()
+        .read()
+        .map(|request| match request {
+            NavRequest::NextTab => 1,
+            NavRequest::PreviousTab => -1,
+            _ => 0,
+        })
+        .sum::<isize>();
+    if steps != 0 && !nav_state.lock_scope().actions {
+        let current_tabs = nav_state
+            .menu
+            .and_then(|menu| {
+                tabs_query
+                    .contains(menu)
+                    .then_some(menu)
+                    .or_else(|| find_tabs(menu, &parent_query, &tabs_query))
+            })
+            .or_else(|| tabs_query.iter().next().map(|(entity, _)| entity));
+        let is_blocked = nav_state.menu.is_some_and(|menu| {
+            menu_query
+                .get(menu)
+                .is_ok_and(|(_, nav_menu)| nav_menu.is_locked)
+                && find_tabs(menu, &parent_query, &tabs_query) != current_tabs
+        });
+        if let (Some(tabs_entity), false) = (current_tabs, is_blocked) {
+            if let Ok((_, tabs)) = tabs_query.get(tabs_entity) {
+                let len = tabs_in_order(
+                    tabs_entity,
+                    &tab_query,
+                    &parent_query,
+                    &children_query,
+                    &tabs_query,
+                )
+                .len() as isize;
+                if len > 0 {
+                    let index = tabs.selected as isize + steps;
+                    let index = if tabs.is_wrap {
+                        index.rem_euclid(len)
+                    } else {
+                        index.clamp(0, len - 1)
+                    };
+                    selections.push((tabs_entity, index as usize, true));
+                }
+            }
+        }
+    }
+
+    for (tabs_entity, index, move_focus) in selections {
+        let tabs = tabs_in_order(
+            tabs_entity,
+            &tab_query,
+            &parent_query,
+            &children_query,
+            &tabs_query,
+        );
+        let Ok((_, mut nav_tabs)) = tabs_query.get_mut(tabs_entity) else {
+            continue;
+        };
+        let Some(tab) = tabs.get(index).and_then(|e| tab_query.get(*e).ok()) else {
+            continue;
+        };
+        if nav_tabs.selected != index {
+            nav_tabs.selected = index;
+            changed_writer.write(UiNavTabChangedEvent {
+                tabs: tabs_entity,
+                tab: tabs[index],
+                index,
+            });
+        }
+
+        // Focus on the panel's menu. The focusables in the panel are not visible until the layout is updated, which
+        // will refresh focus onto them once they are.
+        if move_focus {
+            let panel_menu = menu_query
+                .contains(tab.panel)
+                .then_some(tab.panel)
+                .or_else(|| {
+                    children_query
+                        .iter_descendants(tab.panel)
+                        .find(|e| menu_query.contains(*e))
+                });
+            if let Some(panel_menu) = panel_menu {
+                if nav_state.menu != Some(panel_menu) {
+                    requests.p1().write(NavRequest::SetFocus {
+                        entity: panel_menu,
+                        interaction_type: UiNavInteractionType::Button,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// System that updates the selected state of tabs, and shows or hides their panels.
+pub(crate) fn update_tab_panels(
+    tabs_query: Query<(Entity, &NavTabs)>,
+    mut tab_query: Query<&mut NavTab>,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    mut node_query: Query<&mut Node>,
+) {
+    for (tabs_entity, nav_tabs) in tabs_query.iter() {
+        let tabs = tabs_in_order(
+            tabs_entity,
+            &tab_query.as_readonly(),
+            &parent_query,
+            &children_query,
+            &tabs_query,
+        );
+        for (index, entity) in tabs.into_iter().enumerate() {
+            let Ok(mut tab) = tab_query.get_mut(entity) else {
+                continue;
+            };
+            let is_selected = index == nav_tabs.selected;
+            if tab.is_selected != is_selected {
+                tab.is_selected = is_selected;
+            }
+            if let Ok(mut node) = node_query.get_mut(tab.panel) {
+                let display = if is_selected {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                if node.display != display {
+                    node.display = display;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::{keyboard::Key, ButtonState};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn tab_actions_switch_panels_and_focus() {
+        let mut app = test_app();
+        let root = app.world_mut().spawn(NavTabs::default()).id();
+        let mut panels = Vec::new();
+        let mut buttons = Vec::new();
+        for i in 0..2 {
+            let mut button = Entity::PLACEHOLDER;
+            let panel = app
+                .world_mut()
+                .spawn((ChildOf(root), NavMenu::default(), Node::default()))
+                .with_children(|p| {
+                    button = p
+                        .spawn((
+                            Focusable::default(),
+                            focus_node(Vec2::new(100. * i as f32, 100.), Vec2::new(50., 20.)),
+                        ))
+                        .id();
+                })
+                .id();
+            panels.push(panel);
+            buttons.push(button);
+        }
+        let mut tabs = Vec::new();
+        app.world_mut()
+            .spawn((ChildOf(root), NavMenu::default().prioritized()))
+            .with_children(|p| {
+                for (i, panel) in panels.iter().enumerate() {
+                    tabs.push(
+                        p.spawn((
+                            NavTab::new(*panel),
+                            Focusable::default().with_priority(i == 0),
+                            focus_node(Vec2::new(100. * i as f32, 0.), Vec2::new(50., 20.)),
+                        ))
+                        .id(),
+                    );
+                }
+            });
+        app.update();
+        app.update();
+
+        let display = |app: &App, i: usize| app.world().get::<Node>(panels[i]).unwrap().display;
+        assert!(app.world().get::<NavTab>(tabs[0]).unwrap().is_selected());
+        assert_eq!(display(&app, 0), Display::Flex);
+        assert_eq!(display(&app, 1), Display::None);
+        assert_eq!(focused(&mut app), Some(tabs[0]));
+
+        // Ctrl+Tab selects the next tab and focus moves into its panel
+        send_key(
+            &mut app,
+            KeyCode::ControlLeft,
+            Key::Control,
+            ButtonState::Pressed,
+        );
+        tap_key(&mut app, KeyCode::Tab, Key::Tab);
+        assert_eq!(app.world().get::<NavTabs>(root).unwrap().selected(), 1);
+        assert!(app.world().get::<NavTab>(tabs[1]).unwrap().is_selected());
+        assert_eq!(display(&app, 0), Display::None);
+        assert_eq!(display(&app, 1), Display::Flex);
+        assert_eq!(focused(&mut app), Some(buttons[1]));
+        assert_eq!(
+            read_events::<UiNavTabChangedEvent>(&app),
+            vec![UiNavTabChangedEvent {
+                tabs: root,
+                tab: tabs[1],
+                index: 1
+            }]
+        );
+
+        // Ctrl+Shift+Tab selects the previous tab
+        send_key(
+            &mut app,
+            KeyCode::ShiftLeft,
+            Key::Shift,
+            ButtonState::Pressed,
+        );
+        tap_key(&mut app, KeyCode::Tab, Key::Tab);
+        assert_eq!(app.world().get::<NavTabs>(root).unwrap().selected(), 0);
+        assert_eq!(focused(&mut app), Some(buttons[0]));
+        send_key(
+            &mut app,
+            KeyCode::ShiftLeft,
+            Key::Shift,
+            ButtonState::Released,
+        );
+        send_key(
+            &mut app,
+            KeyCode::ControlLeft,
+            Key::Control,
+            ButtonState::Released,
+        );
+
+        // the gamepad shoulder buttons switch tabs too
+        let pad = app.world_mut().spawn(Gamepad::default()).id();
+        tap_gamepad_button(&mut app, pad, GamepadButton::RightTrigger);
+        assert_eq!(app.world().get::<NavTabs>(root).unwrap().selected(), 1);
+        assert_eq!(focused(&mut app), Some(buttons[1]));
+        tap_gamepad_button(&mut app, pad, GamepadButton::LeftTrigger);
+        assert_eq!(app.world().get::<NavTabs>(root).unwrap().selected(), 0);
+        assert_eq!(focused(&mut app), Some(buttons[0]));
+    }
+}
@@ -0,0 +1,177 @@
+//! Utilities for running the UI navigation plugin headlessly in tests.
+
+use bevy::{
+    input::{
+        gamepad::{RawGamepadButtonChangedEvent, RawGamepadEvent},
+        keyboard::{Key, KeyboardInput},
+        mouse::MouseButtonInput,
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+    ui::RelativeCursorPosition,
+    window::CursorMoved,
+};
+
+use crate::prelude::*;
+
+/// Creates an app running [`BevyUiNavPlugin`] without a window or renderer.
+pub(crate) fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, BevyUiNavPlugin))
+        .add_event::<CursorMoved>()
+        .add_event::<MouseButtonInput>();
+    app
+}
+
+/// Returns the components a focusable needs to be navigable without the UI layout systems. `position` is the center
+/// of the node.
+pub(crate) fn focus_node(position: Vec2, size: Vec2) -> impl Bundle {
+    (
+        ComputedNode {
+            size,
+            inverse_scale_factor: 1.,
+            ..default()
+        },
+        GlobalTransform::from_translation(position.extend(0.)),
+        InheritedVisibility::VISIBLE,
+    )
+}
+
+/// Sends a keyboard event and runs a single update.
+pub(crate) fn send_key(app: &mut App, key_code: KeyCode, logical_key: Key, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+    app.update();
+}
+
+/// Presses and releases a key, then runs an extra update so that requests sent after `UiNavSet` are handled.
+pub(crate) fn tap_key(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    send_key(app, key_code, logical_key.clone(), ButtonState::Pressed);
+    send_key(app, key_code, logical_key, ButtonState::Released);
+    app.update();
+}
+
+/// Sends a mouse button event and runs a single update.
+pub(crate) fn send_mouse_button(app: &mut App, button: MouseButton, state: ButtonState) {
+    app.world_mut().send_event(MouseButtonInput {
+        button,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+    app.update();
+}
+
+/// Sets the value of a gamepad button through the input plugin and runs a single update. Unlike pressing the button on
+/// the `Gamepad` directly, this keeps the "just pressed" state until the next frame.
+pub(crate) fn send_gamepad_button(
+    app: &mut App,
+    gamepad: Entity,
+    button: GamepadButton,
+    value: f32,
+) {
+    app.world_mut()
+        .send_event(RawGamepadEvent::Button(RawGamepadButtonChangedEvent::new(
+            gamepad, button, value,
+        )));
+    app.update();
+}
+
+/// Presses and releases a gamepad button, then runs an extra update so that requests sent after `UiNavSet` are handled.
+pub(crate) fn tap_gamepad_button(app: &mut App, gamepad: Entity, button: GamepadButton) {
+    send_gamepad_button(app, gamepad, button, 1.);
+    send_gamepad_button(app, gamepad, button, 0.);
+    app.update();
+}
+
+/// Sets the mouse interaction of a focusable as the UI focus systems would, with the cursor over it unless the
+/// interaction is `None`, then moves the cursor and runs a single update.
+pub(crate) fn set_interaction(app: &mut App, entity: Entity, interaction: Interaction) {
+    let normalized = (interaction != Interaction::None).then_some(Vec2::splat(0.5));
+    app.world_mut().entity_mut(entity).insert((
+        interaction,
+        RelativeCursorPosition {
+            normalized_visible_node_rect: Rect::new(0., 0., 1., 1.),
+            normalized,
+        },
+    ));
+    app.world_mut().send_event(CursorMoved {
+        window: Entity::PLACEHOLDER,
+        position: Vec2::ZERO,
+        delta: None,
+    });
+    app.update();
+}
+
+/// Returns all unread events of type `T`.
+///
+/// NOTE: Events are not cleared between updates without a window, use [`clear_events`] to reset them.
+pub(crate) fn read_events<T: Event + Clone>(app: &App) -> Vec<T> {
+    let events = app.world().resource::<Events<T>>();
+    events.get_cursor().read(events).cloned().collect()
+}
+
+/// Clears all events of type `T`.
+pub(crate) fn clear_events<T: Event>(app: &mut App) {
+    app.world_mut().resource_mut::<Events<T>>().clear();
+}
+
+/// Returns the entity of the focused non mouse-only focusable.
+pub(crate) fn focused(app: &mut App) -> Option<Entity> {
+    app.world_mut()
+        .query::<(Entity, &Focusable)>()
+        .iter(app.world())
+        .find(|(_, focusable)| focusable.is_focused && !focusable.is_mouse_only)
+        .map(|(entity, _)| entity)
+}
+
+/// Spawns a menu with `n` focusables of 100x20 pixels in a column starting at `origin`, 50 pixels apart. The first
+/// focusable has priority, and `menu` should contain a [`NavMenu`].
+pub(crate) fn spawn_column(
+    app: &mut App,
+    menu: impl Bundle,
+    origin: Vec2,
+    n: usize,
+) -> (Entity, Vec<Entity>) {
+    let mut buttons = Vec::new();
+    let menu = app
+        .world_mut()
+        .spawn(menu)
+        .with_children(|p| {
+            for i in 0..n {
+                buttons.push(
+                    p.spawn((
+                        Focusable::default().with_priority(i == 0),
+                        focus_node(origin + Vec2::new(0., 50. * i as f32), Vec2::new(100., 20.)),
+                    ))
+                    .id(),
+                );
+            }
+        })
+        .id();
+    (menu, buttons)
+}
+
+/// Spawns `n_menus` wrapping menus side by side, 200 pixels apart, each with a column of `n` focusables. The first
+/// menu has priority.
+pub(crate) fn spawn_menus(
+    app: &mut App,
+    n_menus: usize,
+    n: usize,
+) -> (Vec<Entity>, Vec<Vec<Entity>>) {
+    (0..n_menus)
+        .map(|i| {
+            spawn_column(
+                app,
+                NavMenu::new(i == 0, true),
+                Vec2::new(200. * i as f32, 0.),
+                n,
+            )
+        })
+        .unzip()
+}
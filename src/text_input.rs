@@ -0,0 +1,634 @@
+use std::ops::Range;
+
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+};
+
+use crate::{
+    components::Focusable,
+    events::*,
+    types::{NavLockReason, NavLockScope},
+    utils::find_text_entity,
+};
+
+/// Character drawn at the caret position while a [`NavTextInput`] is being edited.
+const CARET: char = '|';
+
+/// Component for a focusable text-entry control.
+///
+/// Clicking the control starts editing, which locks navigation and captures keyboard text until `Enter` commits the
+/// value or `Escape` reverts it. Moving focus away, for example by clicking another focusable, reverts the value. Keys
+/// pressed while editing are recorded in [`UiNavConsumedInput`]. The value is displayed in the entity's `Text`, or the
+/// `Text` of its first child that has one.
+///
+/// [`UiNavConsumedInput`]: crate::prelude::UiNavConsumedInput
+#[derive(Component, Default, Debug, Clone, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[require(Focusable)]
+pub struct NavTextInput {
+    /// The current value
+    pub(crate) value: String,
+    /// The value when editing started, restored when editing is cancelled
+    pub(crate) committed_value: String,
+    /// The caret position, in characters
+    pub(crate) caret: usize,
+    /// The other end of the selection, in characters. The selection spans from here to the caret.
+    pub(crate) selection_anchor: Option<usize>,
+    /// Whether the control is currently being edited
+    pub(crate) is_editing: bool,
+    /// The maximum number of characters allowed
+    pub max_length: Option<usize>,
+    /// Filter deciding which characters are allowed
+    #[reflect(ignore)]
+    pub filter: Option<fn(char) -> bool>,
+}
+
+impl NavTextInput {
+    /// Creates a new `NavTextInput` with an initial value.
+    pub fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
+        Self {
+            committed_value: value.clone(),
+            value,
+            ..default()
+        }
+    }
+
+    /// Sets the `max_length` value and returns the `NavTextInput`.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Sets the `filter` value and returns the `NavTextInput`.
+    pub fn with_filter(mut self, filter: fn(char) -> bool) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Returns the current value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value, ending any edit without committing it.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.committed_value.clone_from(&self.value);
+        self.caret = self.len();
+        self.selection_anchor = None;
+    }
+
+    /// Returns whether the control is currently being edited.
+    pub fn is_editing(&self) -> bool {
+        self.is_editing
+    }
+
+    /// Returns the caret position, in characters.
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Returns the selected range, in characters.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.selection_anchor
+            .filter(|anchor| *anchor != self.caret)
+            .map(|anchor| anchor.min(self.caret)..anchor.max(self.caret))
+    }
+
+    /// Returns the text to display, including the caret while editing.
+    pub fn display_text(&self) -> String {
+        if self.is_editing {
+            let index = self.byte_index(self.caret);
+            let mut text = self.value.clone();
+            text.insert(index, CARET);
+            text
+        } else {
+            self.value.clone()
+        }
+    }
+
+    /// Number of characters in the value.
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    /// Converts a character index into a byte index into `value`.
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(self.value.len())
+    }
+
+    /// Starts editing, remembering the value to restore if editing is cancelled.
+    pub(crate) fn begin_edit(&mut self) {
+        self.is_editing = true;
+        self.committed_value.clone_from(&self.value);
+        self.caret = self.len();
+        self.selection_anchor = None;
+    }
+
+    /// Ends editing, keeping the current value.
+    pub(crate) fn commit(&mut self) {
+        self.is_editing = false;
+        self.committed_value.clone_from(&self.value);
+        self.selection_anchor = None;
+    }
+
+    /// Ends editing, restoring the value from when editing started.
+    pub(crate) fn revert(&mut self) {
+        self.is_editing = false;
+        self.value.clone_from(&self.committed_value);
+        self.caret = self.len();
+        self.selection_anchor = None;
+    }
+
+    /// Removes the selected text, returning whether anything was removed.
+    fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.selection() else {
+            self.selection_anchor = None;
+            return false;
+        };
+        let start = self.byte_index(range.start);
+        let end = self.byte_index(range.end);
+        self.value.replace_range(start..end, "");
+        self.caret = range.start;
+        self.selection_anchor = None;
+        true
+    }
+
+    /// Inserts text at the caret, replacing the selection. Characters rejected by `filter` or exceeding `max_length`
+    /// are dropped.
+    pub(crate) fn insert(&mut self, text: &str) {
+        self.delete_selection();
+        for c in text.chars() {
+            if c.is_control() || self.filter.is_some_and(|filter| !filter(c)) {
+                continue;
+            }
+            if self.max_length.is_some_and(|max| self.len() >= max) {
+                break;
+            }
+            let index = self.byte_index(self.caret);
+            self.value.insert(index, c);
+            self.caret += 1;
+        }
+    }
+
+    /// Deletes the selection, or the character before the caret.
+    pub(crate) fn backspace(&mut self) {
+        if !self.delete_selection() && self.caret > 0 {
+            self.caret -= 1;
+            let index = self.byte_index(self.caret);
+            self.value.remove(index);
+        }
+    }
+
+    /// Deletes the selection, or the character after the caret.
+    pub(crate) fn delete(&mut self) {
+        if !self.delete_selection() && self.caret < self.len() {
+            let index = self.byte_index(self.caret);
+            self.value.remove(index);
+        }
+    }
+
+    /// Moves the caret to `caret`, extending the selection if `select` is true.
+    pub(crate) fn move_caret(&mut self, caret: usize, select: bool) {
+        if select {
+            self.selection_anchor.get_or_insert(self.caret);
+        } else {
+            self.selection_anchor = None;
+        }
+        self.caret = caret.min(self.len());
+    }
+
+    /// Moves the caret one character left. Without `select`, collapses the selection to its start.
+    pub(crate) fn move_left(&mut self, select: bool) {
+        match (select, self.selection()) {
+            (false, Some(range)) => self.move_caret(range.start, false),
+            _ => self.move_caret(self.caret.saturating_sub(1), select),
+        }
+    }
+
+    /// Moves the caret one character right. Without `select`, collapses the selection to its end.
+    pub(crate) fn move_right(&mut self, select: bool) {
+        match (select, self.selection()) {
+            (false, Some(range)) => self.move_caret(range.end, false),
+            _ => self.move_caret(self.caret + 1, select),
+        }
+    }
+
+    /// Selects the whole value.
+    pub(crate) fn select_all(&mut self) {
+        self.selection_anchor = Some(0);
+        self.caret = self.len();
+    }
+}
+
+/// System that starts editing a [`NavTextInput`] when it is clicked.
+pub(crate) fn handle_text_input_clicks(
+    mut events: EventReader<UiNavClickEvent>,
+    mut query: Query<&mut NavTextInput>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+) {
+    for event in events.read() {
+        if let Ok(mut text_input) = query.get_mut(event.0) {
+            if !text_input.is_editing {
+                text_input.begin_edit();
+                // the mouse stays unlocked, so that clicking elsewhere ends the edit
+                nav_request_writer.write(NavRequest::LockWith {
+                    reason: NavLockReason::Entity(event.0),
+                    scope: NavLockScope {
+                        movement: true,
+                        actions: true,
+                        mouse: false,
+                    },
+                });
+            }
+        }
+    }
+}
+
+/// System that applies keyboard input to the [`NavTextInput`] being edited.
+///
+/// NOTE: This runs after `UiNavSet`, so the `UnlockWith` request sent on `Enter` or `Escape` is handled next frame. The
+///  `Enter` and `Escape` presses are handled while navigation is still locked, so they are not seen as a click or a
+///  cancel.
+pub(crate) fn handle_text_input_keyboard(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut events: EventReader<KeyboardInput>,
+    mut query: Query<(Entity, &mut NavTextInput)>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+    mut changed_writer: EventWriter<UiNavTextInputChangedEvent>,
+    mut submit_writer: EventWriter<UiNavTextInputSubmitEvent>,
+) {
+    let select = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let control = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    for event in events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        for (entity, mut text_input) in query.iter_mut() {
+            if !text_input.is_editing {
+                continue;
+            }
+
+            let previous_value = text_input.value.clone();
+            match &event.logical_key {
+                Key::Character(c) if control && c.eq_ignore_ascii_case("a") => {
+                    text_input.select_all();
+                }
+                // ignore other shortcuts
+                Key::Character(_) if control => (),
+                Key::Character(c) => text_input.insert(c),
+                Key::Space => text_input.insert(" "),
+                Key::Backspace => text_input.backspace(),
+                Key::Delete => text_input.delete(),
+                Key::ArrowLeft => text_input.move_left(select),
+                Key::ArrowRight => text_input.move_right(select),
+                Key::Home => text_input.move_caret(0, select),
+                Key::End => {
+                    let end = text_input.len();
+                    text_input.move_caret(end, select);
+                }
+                Key::Enter => {
+                    text_input.commit();
+                    nav_request_writer.write(NavRequest::UnlockWith(NavLockReason::Entity(entity)));
+                    submit_writer.write(UiNavTextInputSubmitEvent {
+                        entity,
+                        value: text_input.value.clone(),
+                    });
+                }
+                Key::Escape => {
+                    text_input.revert();
+                    nav_request_writer.write(NavRequest::UnlockWith(NavLockReason::Entity(entity)));
+                }
+                _ => (),
+            }
+
+            if text_input.value != previous_value {
+                changed_writer.write(UiNavTextInputChangedEvent {
+                    entity,
+                    value: text_input.value.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// System that ends editing a [`NavTextInput`] when it stops being the focused entity, and releases the navigation lock
+/// of text inputs that were removed or despawned.
+///
+/// NOTE: An edit ended this way is reverted, like with `Escape`, since only `Enter` submits the value.
+pub(crate) fn end_interrupted_text_input_edits(
+    mut removed: RemovedComponents<NavTextInput>,
+    mut query: Query<(Entity, &mut NavTextInput, &Focusable)>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+) {
+    for entity in removed.read() {
+        nav_request_writer.write(NavRequest::UnlockWith(NavLockReason::Entity(entity)));
+    }
+    for (entity, mut text_input, focusable) in query.iter_mut() {
+        if text_input.is_editing
+            && (!focusable.active() || !focusable.is_visible || focusable.is_disabled)
+        {
+            text_input.revert();
+            nav_request_writer.write(NavRequest::UnlockWith(NavLockReason::Entity(entity)));
+        }
+    }
+}
+
+/// System that displays the value of changed [`NavTextInput`] components in their `Text`.
+pub(crate) fn update_text_input_display(
+    query: Query<(Entity, &NavTextInput, Option<&Children>), Changed<NavTextInput>>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (entity, text_input, children) in query.iter() {
+        let target = find_text_entity(entity, children, &text_query);
+        if let Some(mut text) = target.and_then(|e| text_query.get_mut(e).ok()) {
+            let display_text = text_input.display_text();
+            if text.0 != display_text {
+                text.0 = display_text;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, resources::UiNavState, test_utils::*};
+
+    fn editing(value: &str) -> NavTextInput {
+        let mut text_input = NavTextInput::new(value);
+        text_input.begin_edit();
+        text_input
+    }
+
+    #[test]
+    fn insert_respects_max_length_and_filter() {
+        let mut text_input = editing("ab").with_max_length(4);
+        text_input.insert("cdef");
+        assert_eq!(text_input.value(), "abcd");
+        assert_eq!(text_input.caret(), 4);
+
+        let mut text_input = editing("").with_filter(|c| c.is_ascii_digit());
+        text_input.insert("1a2b3");
+        assert_eq!(text_input.value(), "123");
+    }
+
+    #[test]
+    fn caret_editing_works_with_multibyte_characters() {
+        let mut text_input = editing("héllo");
+        text_input.move_caret(2, false);
+        text_input.backspace();
+        assert_eq!(text_input.value(), "hllo");
+        text_input.insert("é");
+        text_input.delete();
+        assert_eq!(text_input.value(), "hélo");
+        assert_eq!(text_input.display_text(), "hé|lo");
+    }
+
+    #[test]
+    fn selection_is_replaced_and_deleted() {
+        let mut text_input = editing("hello world");
+        text_input.move_caret(0, false);
+        text_input.move_caret(5, true);
+        assert_eq!(text_input.selection(), Some(0..5));
+        text_input.insert("goodbye");
+        assert_eq!(text_input.value(), "goodbye world");
+        assert_eq!(text_input.selection(), None);
+
+        text_input.select_all();
+        text_input.backspace();
+        assert_eq!(text_input.value(), "");
+    }
+
+    #[test]
+    fn arrows_collapse_selection() {
+        let mut text_input = editing("abcdef");
+        text_input.move_caret(1, false);
+        text_input.move_right(true);
+        text_input.move_right(true);
+        assert_eq!(text_input.selection(), Some(1..3));
+        text_input.move_left(false);
+        assert_eq!(text_input.caret(), 1);
+        assert_eq!(text_input.selection(), None);
+    }
+
+    #[test]
+    fn revert_restores_value() {
+        let mut text_input = editing("name");
+        text_input.insert("!");
+        text_input.revert();
+        assert_eq!(text_input.value(), "name");
+        assert!(!text_input.is_editing());
+
+        let mut text_input = editing("name");
+        text_input.insert("!");
+        text_input.commit();
+        text_input.begin_edit();
+        text_input.backspace();
+        text_input.revert();
+        assert_eq!(text_input.value(), "name!");
+    }
+
+    fn test_app_with_text_input() -> (App, Entity) {
+        let mut app = test_app();
+        let mut text_input = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(NavMenu::default().prioritized())
+            .with_children(|p| {
+                text_input = p
+                    .spawn((
+                        NavTextInput::new("name"),
+                        Focusable::prioritized(),
+                        focus_node(Vec2::ZERO, Vec2::new(200., 50.)),
+                    ))
+                    .id();
+            });
+        app.update();
+        app.update();
+        (app, text_input)
+    }
+
+    fn clear_click_and_cancel_events(app: &mut App) {
+        clear_events::<UiNavClickEvent>(app);
+        clear_events::<UiNavCancelEvent>(app);
+    }
+
+    #[test]
+    fn enter_and_escape_do_not_leak_while_editing() {
+        let (mut app, entity) = test_app_with_text_input();
+
+        // Enter clicks the focused text input, which starts editing
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert!(app
+            .world()
+            .get::<NavTextInput>(entity)
+            .unwrap()
+            .is_editing());
+
+        // Enter commits without clicking the text input again
+        clear_click_and_cancel_events(&mut app);
+        tap_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        let text_input = app.world().get::<NavTextInput>(entity).unwrap();
+        assert!(!text_input.is_editing());
+        assert_eq!(text_input.value(), "namea");
+        assert_eq!(read_events::<UiNavClickEvent>(&app).len(), 0);
+        assert_eq!(read_events::<UiNavTextInputSubmitEvent>(&app).len(), 1);
+
+        // Escape reverts without sending a cancel event
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        tap_key(&mut app, KeyCode::Backspace, Key::Backspace);
+        clear_click_and_cancel_events(&mut app);
+        tap_key(&mut app, KeyCode::Escape, Key::Escape);
+        let text_input = app.world().get::<NavTextInput>(entity).unwrap();
+        assert!(!text_input.is_editing());
+        assert_eq!(text_input.value(), "namea");
+        assert_eq!(read_events::<UiNavCancelEvent>(&app).len(), 0);
+
+        // Navigation is unlocked again, so Escape is now a cancel
+        tap_key(&mut app, KeyCode::Escape, Key::Escape);
+        assert_eq!(read_events::<UiNavCancelEvent>(&app).len(), 1);
+    }
+
+    fn is_editing(app: &App, entity: Entity) -> bool {
+        app.world()
+            .get::<NavTextInput>(entity)
+            .unwrap()
+            .is_editing()
+    }
+
+    fn has_locks(app: &App) -> bool {
+        !app.world().resource::<UiNavState>().locks.is_empty()
+    }
+
+    #[test]
+    fn despawning_while_editing_releases_the_lock() {
+        let (mut app, entity) = test_app_with_text_input();
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert!(is_editing(&app, entity));
+        assert!(has_locks(&app));
+
+        app.world_mut().entity_mut(entity).despawn();
+        app.update();
+        app.update();
+        assert!(!has_locks(&app));
+    }
+
+    #[test]
+    fn losing_focus_while_editing_reverts_and_releases_the_lock() {
+        let (mut app, entity) = test_app_with_text_input();
+        let menu = app.world().get::<ChildOf>(entity).unwrap().parent();
+        let button = app
+            .world_mut()
+            .spawn((
+                Focusable::default(),
+                focus_node(Vec2::new(0., 100.), Vec2::new(200., 50.)),
+                ChildOf(menu),
+            ))
+            .id();
+        app.update();
+
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        tap_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+        assert!(is_editing(&app, entity));
+
+        app.world_mut().send_event(NavRequest::SetFocus {
+            entity: button,
+            interaction_type: UiNavInteractionType::Manual,
+        });
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), Some(button));
+        let text_input = app.world().get::<NavTextInput>(entity).unwrap();
+        assert!(!text_input.is_editing());
+        assert_eq!(text_input.value(), "name");
+        assert!(!has_locks(&app));
+    }
+
+    #[test]
+    fn keys_are_consumed_while_editing() {
+        let (mut app, entity) = test_app_with_text_input();
+        app.insert_resource(UiNavSettings {
+            clear_consumed_input: true,
+            ..default()
+        });
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert!(is_editing(&app, entity));
+
+        // typed keys and the actions mapped to them are consumed and cleared, even though navigation is locked
+        for (keycode, key) in [
+            (KeyCode::KeyW, Key::Character("w".into())),
+            (KeyCode::Space, Key::Space),
+        ] {
+            send_key(&mut app, keycode, key.clone(), ButtonState::Pressed);
+            let consumed = app.world().resource::<UiNavConsumedInput>();
+            assert!(consumed.key_consumed(keycode));
+            let keys = app.world().resource::<ButtonInput<KeyCode>>();
+            assert!(keys.pressed(keycode));
+            assert!(!keys.just_pressed(keycode));
+            send_key(&mut app, keycode, key, ButtonState::Released);
+        }
+        assert!(app
+            .world()
+            .resource::<UiNavConsumedInput>()
+            .consumed(ActionType::Action));
+        assert_eq!(
+            app.world().get::<NavTextInput>(entity).unwrap().value(),
+            "namew "
+        );
+
+        // keys are no longer consumed once editing ends
+        tap_key(&mut app, KeyCode::Escape, Key::Escape);
+        app.update();
+        send_key(
+            &mut app,
+            KeyCode::KeyW,
+            Key::Character("w".into()),
+            ButtonState::Pressed,
+        );
+        assert!(!app
+            .world()
+            .resource::<UiNavConsumedInput>()
+            .key_consumed(KeyCode::KeyW));
+    }
+
+    #[test]
+    fn clicking_elsewhere_ends_the_edit() {
+        let (mut app, entity) = test_app_with_text_input();
+        let menu = app.world().get::<ChildOf>(entity).unwrap().parent();
+        let button = app
+            .world_mut()
+            .spawn((
+                Focusable::default(),
+                focus_node(Vec2::new(0., 100.), Vec2::new(200., 50.)),
+                ChildOf(menu),
+            ))
+            .id();
+        app.update();
+        tap_key(&mut app, KeyCode::Enter, Key::Enter);
+        assert!(is_editing(&app, entity));
+
+        // the mouse is not locked while editing
+        set_interaction(&mut app, button, Interaction::Pressed);
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), Some(button));
+        assert!(!is_editing(&app, entity));
+        assert!(!has_locks(&app));
+    }
+}
@@ -0,0 +1,246 @@
+use bevy::{
+    ecs::query::{QueryData, QueryFilter},
+    prelude::*,
+};
+
+use crate::{components::*, events::*};
+
+/// Component for a focusable checkbox or switch.
+///
+/// The `checked` state is flipped when the focusable is clicked, and a [`UiNavToggleChangedEvent`] is sent.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(Focusable)]
+pub struct NavToggle {
+    pub checked: bool,
+}
+
+impl NavToggle {
+    pub fn new(checked: bool) -> Self {
+        Self { checked }
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Flips the `checked` state.
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+}
+
+/// Component for a container of [`NavRadioButton`] focusables, of which exactly one is selected.
+///
+/// Radio buttons belong to their nearest `NavRadioGroup` ancestor. If no button is selected when the group is spawned,
+/// the first button selected with [`NavRadioButton::selected`] is used, otherwise the first button in the hierarchy.
+///
+/// A [`UiNavRadioGroupChangedEvent`] is sent when the user clicks a button that is not selected.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct NavRadioGroup {
+    pub(crate) selected: Option<Entity>,
+}
+
+impl NavRadioGroup {
+    /// Returns the selected radio button.
+    pub fn selected(&self) -> Option<Entity> {
+        self.selected
+    }
+
+    /// Selects a radio button. No event is sent.
+    pub fn select(&mut self, entity: Entity) {
+        self.selected = Some(entity);
+    }
+}
+
+/// Component for a focusable radio button within a [`NavRadioGroup`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(Focusable)]
+pub struct NavRadioButton {
+    pub(crate) is_selected: bool,
+}
+
+impl NavRadioButton {
+    /// Creates a radio button that is initially selected in its group.
+    pub fn selected() -> Self {
+        Self { is_selected: true }
+    }
+
+    /// Returns whether this is the selected button in its group.
+    pub fn is_selected(&self) -> bool {
+        self.is_selected
+    }
+}
+
+/// Returns the nearest [`NavRadioGroup`] ancestor of an entity.
+fn find_radio_group<D: QueryData, F: QueryFilter>(
+    entity: Entity,
+    parent_query: &Query<&ChildOf>,
+    group_query: &Query<D, F>,
+) -> Option<Entity> {
+    parent_query
+        .iter_ancestors(entity)
+        .find(|e| group_query.contains(*e))
+}
+
+/// System that handles clicks on toggles and radio buttons.
+pub(crate) fn handle_toggle_clicks(
+    mut events: EventReader<UiNavClickEvent>,
+    mut toggle_query: Query<&mut NavToggle>,
+    button_query: Query<(), With<NavRadioButton>>,
+    parent_query: Query<&ChildOf>,
+    mut group_query: Query<&mut NavRadioGroup>,
+    mut toggle_writer: EventWriter<UiNavToggleChangedEvent>,
+    mut radio_writer: EventWriter<UiNavRadioGroupChangedEvent>,
+) {
+    for event in events.read() {
+        let entity = event.0;
+        if let Ok(mut toggle) = toggle_query.get_mut(entity) {
+            toggle.toggle();
+            toggle_writer.write(UiNavToggleChangedEvent {
+                entity,
+                checked: toggle.checked,
+            });
+        }
+
+        if !button_query.contains(entity) {
+            continue;
+        }
+        let Some(group_entity) = find_radio_group(entity, &parent_query, &group_query) else {
+            continue;
+        };
+        if let Ok(mut group) = group_query.get_mut(group_entity) {
+            if group.selected != Some(entity) {
+                group.selected = Some(entity);
+                radio_writer.write(UiNavRadioGroupChangedEvent {
+                    group: group_entity,
+                    selected: entity,
+                });
+            }
+        }
+    }
+}
+
+/// System that selects a default button in new radio groups and updates the selected state of radio buttons.
+pub(crate) fn update_radio_buttons(
+    mut group_query: Query<(Entity, &mut NavRadioGroup)>,
+    mut button_query: Query<(Entity, &mut NavRadioButton)>,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+) {
+    // select a default button in groups without a selection
+    let mut defaults: Vec<(Entity, Entity)> = Vec::new();
+    for group_entity in group_query
+        .iter()
+        .filter(|(_, group)| group.selected.is_none())
+        .map(|(entity, _)| entity)
+    {
+        // use the first button in hierarchy order, preferring a button marked as selected
+        let buttons = children_query
+            .iter_descendants(group_entity)
+            .filter(|e| {
+                button_query.contains(*e)
+                    && find_radio_group(*e, &parent_query, &group_query) == Some(group_entity)
+            })
+            .collect::<Vec<_>>();
+        let default = buttons
+            .iter()
+            .find(|e| button_query.get(**e).is_ok_and(|(_, b)| b.is_selected))
+            .or(buttons.first());
+        if let Some(default) = default {
+            defaults.push((group_entity, *default));
+        }
+    }
+    for (group_entity, button_entity) in defaults {
+        if let Ok((_, mut group)) = group_query.get_mut(group_entity) {
+            group.selected = Some(button_entity);
+        }
+    }
+
+    for (button_entity, mut button) in button_query.iter_mut() {
+        let is_selected = find_radio_group(button_entity, &parent_query, &group_query)
+            .and_then(|e| group_query.get(e).ok())
+            .is_some_and(|(_, group)| group.selected == Some(button_entity));
+        button.set_if_neq(NavRadioButton { is_selected });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Sends a click event for an entity and runs an update.
+    fn click(app: &mut App, entity: Entity) {
+        app.world_mut().send_event(UiNavClickEvent(entity));
+        app.update();
+    }
+
+    #[test]
+    fn toggle_flips_on_click() {
+        let mut app = test_app();
+        let toggle = app.world_mut().spawn(NavToggle::new(false)).id();
+        app.update();
+
+        click(&mut app, toggle);
+        assert!(app.world().get::<NavToggle>(toggle).unwrap().is_checked());
+        assert_eq!(
+            read_events::<UiNavToggleChangedEvent>(&app),
+            vec![UiNavToggleChangedEvent {
+                entity: toggle,
+                checked: true
+            }]
+        );
+
+        clear_events::<UiNavClickEvent>(&mut app);
+        click(&mut app, toggle);
+        assert!(!app.world().get::<NavToggle>(toggle).unwrap().is_checked());
+    }
+
+    #[test]
+    fn radio_group_keeps_one_button_selected() {
+        let mut app = test_app();
+        let mut buttons = Vec::new();
+        let group = app
+            .world_mut()
+            .spawn(NavRadioGroup::default())
+            .with_children(|p| {
+                buttons.push(p.spawn(NavRadioButton::default()).id());
+                buttons.push(p.spawn(NavRadioButton::selected()).id());
+                buttons.push(p.spawn(NavRadioButton::default()).id());
+            })
+            .id();
+        app.update();
+
+        let is_selected = |app: &App| {
+            buttons
+                .iter()
+                .map(|e| app.world().get::<NavRadioButton>(*e).unwrap().is_selected())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            app.world().get::<NavRadioGroup>(group).unwrap().selected(),
+            Some(buttons[1])
+        );
+        assert_eq!(is_selected(&app), vec![false, true, false]);
+
+        click(&mut app, buttons[2]);
+        assert_eq!(is_selected(&app), vec![false, false, true]);
+        assert_eq!(
+            read_events::<UiNavRadioGroupChangedEvent>(&app),
+            vec![UiNavRadioGroupChangedEvent {
+                group,
+                selected: buttons[2]
+            }]
+        );
+
+        // clicking the selected button does nothing
+        clear_events::<UiNavClickEvent>(&mut app);
+        clear_events::<UiNavRadioGroupChangedEvent>(&mut app);
+        click(&mut app, buttons[2]);
+        assert_eq!(is_selected(&app), vec![false, false, true]);
+        assert!(read_events::<UiNavRadioGroupChangedEvent>(&app).is_empty());
+    }
+}
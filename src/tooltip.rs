@@ -0,0 +1,243 @@
+use bevy::{prelude::*, time::Stopwatch, window::PrimaryWindow};
+
+use crate::{
+    components::*, context_menu::context_menu_position, popup::node_rect, resources::UiNavSettings,
+    utils::find_text_entity,
+};
+
+/// Component holding the tooltip or description of a [`Focusable`].
+///
+/// The text is shown by every [`NavTooltipDisplay`] once the focusable has been hovered by the mouse or focused for
+/// [`UiNavSettings::tooltip_delay`] seconds. A hovered focusable takes precedence over the focused one.
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Hash)]
+pub struct NavTooltip(pub String);
+
+impl NavTooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self(text.into())
+    }
+}
+
+/// Where a [`NavTooltipDisplay`] is placed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum NavTooltipPlacement {
+    /// Next to the element and hidden while there is no tooltip, like the placement of a [`NavContextMenu`]
+    ///
+    /// [`NavContextMenu`]: crate::prelude::NavContextMenu
+    #[default]
+    Anchored,
+    /// Left where it was spawned, such as a description panel. Its text is cleared while there is no tooltip.
+    Panel,
+}
+
+/// Component for a node that shows the current [`NavTooltip`] in its `Text`, which is either on the node itself or on
+/// its first child with a `Text`.
+///
+/// An anchored display should be spawned as a root node with an absolutely positioned `Node`, because it is moved with
+/// the `left` and `top` of the node. It is hidden until it has been laid out with the new text.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Hash)]
+#[require(Node, Visibility)]
+pub struct NavTooltipDisplay {
+    pub placement: NavTooltipPlacement,
+}
+
+impl NavTooltipDisplay {
+    /// A display placed next to the element.
+    pub const ANCHORED: Self = Self {
+        placement: NavTooltipPlacement::Anchored,
+    };
+
+    /// A display left where it was spawned.
+    pub const PANEL: Self = Self {
+        placement: NavTooltipPlacement::Panel,
+    };
+}
+
+/// Resource tracking which focusable's tooltip is shown.
+#[derive(Resource, Default, Debug, Reflect)]
+#[reflect(Resource, Default, Debug)]
+pub struct UiNavTooltipState {
+    /// The hovered or focused focusable with a tooltip
+    candidate: Option<Entity>,
+    /// Time since the candidate changed
+    timer: Stopwatch,
+    /// The focusable whose tooltip is shown
+    shown: Option<Entity>,
+}
+
+impl UiNavTooltipState {
+    /// Returns the focusable whose tooltip is shown.
+    pub fn shown(&self) -> Option<Entity> {
+        self.shown
+    }
+}
+
+/// System that picks the focusable whose tooltip should be shown, after it has been hovered or focused for the tooltip
+/// delay.
+///
+/// The tooltip is hidden whenever the hovered or focused focusable changes, and while it is pressed.
+pub(crate) fn update_tooltip_target(
+    time: Res<Time<Real>>,
+    settings: Res<UiNavSettings>,
+    query: Query<(Entity, &Focusable), With<NavTooltip>>,
+    mut state: ResMut<UiNavTooltipState>,
+) {
+    let candidate = query
+        .iter()
+        .find(|(_, focusable)| focusable.is_hovered())
+        .or_else(|| {
+            query
+                .iter()
+                .find(|(_, focusable)| focusable.active() && !focusable.is_mouse_only)
+        })
+        .filter(|(_, focusable)| !focusable.is_pressed())
+        .map(|(entity, _)| entity);
+
+    if candidate != state.candidate {
+        state.candidate = candidate;
+        state.timer.reset();
+    } else {
+        state.timer.tick(time.delta());
+    }
+
+    let shown = candidate.filter(|_| state.timer.elapsed_secs() >= settings.tooltip_delay);
+    if shown != state.shown {
+        state.shown = shown;
+    }
+}
+
+/// System that writes the shown tooltip to tooltip displays, and places anchored displays next to the element.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_tooltip_displays(
+    state: Res<UiNavTooltipState>,
+    tooltip_query: Query<(&NavTooltip, &ComputedNode, &GlobalTransform)>,
+    mut query: Query<(
+        Entity,
+        &NavTooltipDisplay,
+        &ComputedNode,
+        &mut Node,
+        &mut Visibility,
+        Option<&Children>,
+    )>,
+    mut text_query: Query<&mut Text>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let tooltip = state.shown.and_then(|e| tooltip_query.get(e).ok());
+    let viewport = window_query
+        .single()
+        .map_or(Vec2::INFINITY, |window| window.size());
+
+    for (entity, display, computed_node, mut node, mut visibility, children) in query.iter_mut() {
+        let value = tooltip.map_or("", |(tooltip, ..)| tooltip.0.as_str());
+        let target = find_text_entity(entity, children, &text_query);
+        let mut is_text_changed = false;
+        if let Some(mut text) = target.and_then(|e| text_query.get_mut(e).ok()) {
+            if text.0 != value {
+                text.0 = value.to_string();
+                is_text_changed = true;
+            }
+        }
+
+        if display.placement != NavTooltipPlacement::Anchored {
+            continue;
+        }
+
+        // wait for the display to be laid out with the new text before showing it
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        let Some((_, owner_node, transform)) =
+            tooltip.filter(|_| !is_text_changed && size.x > f32::EPSILON && size.y > f32::EPSILON)
+        else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+
+        let position = context_menu_position(node_rect(owner_node, transform), size, viewport);
+        if node.left != Val::Px(position.x) || node.top != Val::Px(position.y) {
+            node.left = Val::Px(position.x);
+            node.top = Val::Px(position.y);
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::{input::keyboard::Key, time::TimeUpdateStrategy};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    fn spawn_menu(app: &mut App) -> (Entity, Vec<Entity>) {
+        let panel = app
+            .world_mut()
+            .spawn((NavTooltipDisplay::PANEL, Text::default()))
+            .id();
+        let (_, buttons) = spawn_column(app, NavMenu::default().prioritized(), Vec2::ZERO, 2);
+        for (i, button) in buttons.iter().enumerate() {
+            app.world_mut()
+                .entity_mut(*button)
+                .insert(NavTooltip::new(format!("Button {i}")));
+        }
+        (panel, buttons)
+    }
+
+    fn panel_text(app: &App, panel: Entity) -> &str {
+        app.world().get::<Text>(panel).unwrap().0.as_str()
+    }
+
+    #[test]
+    fn tooltip_of_focused_element_is_shown() {
+        let mut app = test_app();
+        app.insert_resource(UiNavSettings {
+            tooltip_delay: 0.,
+            ..default()
+        });
+        let (panel, _) = spawn_menu(&mut app);
+        app.update();
+        app.update();
+        assert_eq!(panel_text(&app, panel), "Button 0");
+
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(panel_text(&app, panel), "Button 1");
+    }
+
+    #[test]
+    fn tooltip_is_shown_after_delay() {
+        let mut app = test_app();
+        app.insert_resource(UiNavSettings {
+            tooltip_delay: 1.,
+            ..default()
+        })
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            0.4,
+        )));
+        let (panel, buttons) = spawn_menu(&mut app);
+        let shown = |app: &App| app.world().resource::<UiNavTooltipState>().shown();
+        app.update();
+        app.update();
+        assert_eq!(panel_text(&app, panel), "");
+        assert_eq!(shown(&app), None);
+
+        // shown once the focusable has been focused for the delay
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(shown(&app), Some(buttons[0]));
+        assert_eq!(panel_text(&app, panel), "Button 0");
+
+        // hidden when focus moves, until the next focusable has been focused for the delay
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(shown(&app), None);
+        assert_eq!(panel_text(&app, panel), "");
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(shown(&app), Some(buttons[1]));
+        assert_eq!(panel_text(&app, panel), "Button 1");
+    }
+}
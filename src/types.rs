@@ -1,4 +1,4 @@
-use bevy::reflect::Reflect;
+use bevy::{ecs::entity::Entity, prelude::ReflectDefault, reflect::Reflect};
 
 /// Type describing whether an interaction can from a user or internally.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Reflect)]
@@ -52,3 +52,103 @@ pub enum UiNavDirection {
     DownLeft,
     DownRight,
 }
+
+impl UiNavDirection {
+    /// The directions along the axes.
+    pub const CARDINAL: [Self; 4] = [
+        UiNavDirection::Up,
+        UiNavDirection::Down,
+        UiNavDirection::Left,
+        UiNavDirection::Right,
+    ];
+
+    /// Returns the direction pointing the other way.
+    pub fn opposite(&self) -> Self {
+        match *self {
+            UiNavDirection::Up => UiNavDirection::Down,
+            UiNavDirection::Down => UiNavDirection::Up,
+            UiNavDirection::Left => UiNavDirection::Right,
+            UiNavDirection::Right => UiNavDirection::Left,
+            UiNavDirection::UpLeft => UiNavDirection::DownRight,
+            UiNavDirection::UpRight => UiNavDirection::DownLeft,
+            UiNavDirection::DownLeft => UiNavDirection::UpRight,
+            UiNavDirection::DownRight => UiNavDirection::UpLeft,
+        }
+    }
+}
+
+/// Type identifying the holder of a navigation lock, so that it only releases its own locks.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Reflect)]
+#[reflect(Debug, Hash, PartialEq)]
+pub enum NavLockReason {
+    /// The lock taken by `NavRequest::Lock`, which is held at most once
+    Default,
+    /// A lock held by a named system, such as `"cutscene"`
+    Named(&'static str),
+    /// A lock held by an entity, such as a text input while it is being edited
+    Entity(Entity),
+}
+
+/// Type describing which input a navigation lock blocks.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Hash, Reflect)]
+#[reflect(Debug, Default, Hash, PartialEq)]
+pub struct NavLockScope {
+    /// Whether movement between focusables is blocked, including movement claimed by widgets
+    pub movement: bool,
+    /// Whether the action, cancel, tab and context menu actions are blocked
+    pub actions: bool,
+    /// Whether mouse hover and clicks are blocked
+    pub mouse: bool,
+}
+
+impl NavLockScope {
+    /// Blocks all navigation input.
+    pub const ALL: Self = Self {
+        movement: true,
+        actions: true,
+        mouse: true,
+    };
+
+    /// Blocks only movement.
+    pub const MOVEMENT: Self = Self {
+        movement: true,
+        actions: false,
+        mouse: false,
+    };
+
+    /// Blocks only the action, cancel, tab and context menu actions.
+    pub const ACTIONS: Self = Self {
+        movement: false,
+        actions: true,
+        mouse: false,
+    };
+
+    /// Blocks only the mouse.
+    pub const MOUSE: Self = Self {
+        movement: false,
+        actions: false,
+        mouse: true,
+    };
+
+    /// Returns the input blocked by either scope.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            movement: self.movement || other.movement,
+            actions: self.actions || other.actions,
+            mouse: self.mouse || other.mouse,
+        }
+    }
+
+    /// Returns whether no input is blocked.
+    pub fn is_empty(&self) -> bool {
+        !(self.movement || self.actions || self.mouse)
+    }
+}
+
+/// A navigation lock held by a [`NavLockReason`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Reflect)]
+#[reflect(Debug, Hash, PartialEq)]
+pub struct NavLock {
+    pub reason: NavLockReason,
+    pub scope: NavLockScope,
+}
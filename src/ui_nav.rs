@@ -0,0 +1,189 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    components::*,
+    events::NavRequest,
+    resources::UiNavState,
+    types::{FocusState, NavLock, NavLockReason, NavLockScope, UiNavInteractionType},
+};
+
+/// Resource holding the entities that had focus before each menu pushed with [`UiNav::push_menu`].
+#[derive(Resource, Default, Debug)]
+pub(crate) struct UiNavMenuStack(Vec<Entity>);
+
+/// System parameter for querying and commanding UI navigation.
+///
+/// Commands are sent as [`NavRequest`] events and are applied in [`UiNavSet`], so systems using them should be
+/// scheduled before it to take effect in the same frame.
+///
+/// [`UiNavSet`]: crate::prelude::UiNavSet
+#[derive(SystemParam)]
+pub struct UiNav<'w, 's> {
+    state: Res<'w, UiNavState>,
+    menu_stack: ResMut<'w, UiNavMenuStack>,
+    focusables: Query<'w, 's, (Entity, &'static Focusable)>,
+    nav_request_writer: EventWriter<'w, NavRequest>,
+}
+
+impl UiNav<'_, '_> {
+    /// Returns the current menu.
+    pub fn menu(&self) -> Option<Entity> {
+        self.state.menu
+    }
+
+    /// Returns the focused focusable, ignoring mouse-only focusables.
+    pub fn focused(&self) -> Option<Entity> {
+        self.focusables
+            .iter()
+            .find(|(_, focusable)| focusable.active() && !focusable.is_mouse_only)
+            .map(|(entity, _)| entity)
+    }
+
+    /// Returns the focused focusable if it is pressed.
+    pub fn pressed(&self) -> Option<Entity> {
+        self.focusables
+            .iter()
+            .find(|(_, focusable)| focusable.is_pressed() && !focusable.is_mouse_only)
+            .map(|(entity, _)| entity)
+    }
+
+    /// Returns the state of a focusable, or `None` if the entity is not a focusable.
+    pub fn state(&self, entity: Entity) -> Option<FocusState> {
+        self.focusables
+            .get(entity)
+            .ok()
+            .map(|(_, focusable)| focusable.state())
+    }
+
+    /// Returns whether any navigation input is locked.
+    pub fn is_locked(&self) -> bool {
+        !self.state.lock_scope().is_empty()
+    }
+
+    /// Returns the input blocked by all active locks.
+    pub fn lock_scope(&self) -> NavLockScope {
+        self.state.lock_scope()
+    }
+
+    /// Iterates over the active locks in the order they were taken, which is useful for debugging a stuck lock.
+    pub fn locks(&self) -> impl Iterator<Item = &NavLock> {
+        self.state.locks.iter()
+    }
+
+    /// Sets focus on a focusable, making its menu the current menu.
+    pub fn focus(&mut self, entity: Entity) {
+        self.nav_request_writer.write(NavRequest::SetFocus {
+            entity,
+            interaction_type: UiNavInteractionType::Manual,
+        });
+    }
+
+    /// Makes a menu the current menu, focusing its prioritized focusable or otherwise its first focusable.
+    pub fn focus_menu(&mut self, menu: Entity) {
+        self.focus(menu);
+    }
+
+    /// Locks navigation. No effect if already locked.
+    pub fn lock(&mut self) {
+        self.nav_request_writer.write(NavRequest::Lock);
+    }
+
+    /// Unlocks navigation. No effect if already unlocked.
+    pub fn unlock(&mut self) {
+        self.nav_request_writer.write(NavRequest::Unlock);
+    }
+
+    /// Adds a lock held by `reason`, blocking the input in `scope` until every lock is released.
+    pub fn lock_with(&mut self, reason: NavLockReason, scope: NavLockScope) {
+        self.nav_request_writer
+            .write(NavRequest::LockWith { reason, scope });
+    }
+
+    /// Releases the last lock held by `reason`.
+    pub fn unlock_with(&mut self, reason: NavLockReason) {
+        self.nav_request_writer
+            .write(NavRequest::UnlockWith(reason));
+    }
+
+    /// Makes a menu the current menu, remembering the focus to return to with [`UiNav::pop_menu`].
+    pub fn push_menu(&mut self, menu: Entity) {
+        if let Some(previous) = self.focused().or(self.state.menu) {
+            self.menu_stack.0.push(previous);
+        }
+        self.focus_menu(menu);
+    }
+
+    /// Returns focus to the focusable or menu that had it before the last [`UiNav::push_menu`], and returns it.
+    pub fn pop_menu(&mut self) -> Option<Entity> {
+        let previous = self.menu_stack.0.pop()?;
+        self.focus(previous);
+        Some(previous)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::system::RunSystemOnce, input::keyboard::Key};
+
+    use super::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn push_and_pop_menus() {
+        let mut app = test_app();
+        let (menus, buttons) = spawn_menus(&mut app, 2, 2);
+        let buttons: Vec<Entity> = buttons.concat();
+        app.update();
+        app.update();
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+
+        let world = app.world_mut();
+        assert_eq!(
+            world.run_system_once(|nav: UiNav| nav.menu()).unwrap(),
+            Some(menus[0])
+        );
+        assert_eq!(
+            world.run_system_once(|nav: UiNav| nav.focused()).unwrap(),
+            Some(buttons[1])
+        );
+
+        let menu = menus[1];
+        world
+            .run_system_once(move |mut nav: UiNav| nav.push_menu(menu))
+            .unwrap();
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(
+            world.run_system_once(|nav: UiNav| nav.menu()).unwrap(),
+            Some(menus[1])
+        );
+        assert_eq!(
+            world.run_system_once(|nav: UiNav| nav.focused()).unwrap(),
+            Some(buttons[2])
+        );
+
+        assert_eq!(
+            world
+                .run_system_once(|mut nav: UiNav| nav.pop_menu())
+                .unwrap(),
+            Some(buttons[1])
+        );
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(
+            world.run_system_once(|nav: UiNav| nav.menu()).unwrap(),
+            Some(menus[0])
+        );
+        assert_eq!(
+            world.run_system_once(|nav: UiNav| nav.focused()).unwrap(),
+            Some(buttons[1])
+        );
+        let button = buttons[1];
+        assert_eq!(
+            world
+                .run_system_once(move |nav: UiNav| nav.state(button))
+                .unwrap(),
+            Some(FocusState::Focus)
+        );
+    }
+}
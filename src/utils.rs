@@ -1,4 +1,8 @@
-use bevy::{math::bounding::Aabb2d, prelude::*};
+use bevy::{
+    ecs::query::{QueryData, QueryFilter},
+    math::bounding::Aabb2d,
+    prelude::*,
+};
 
 /// Utility that performs linear interpolation between `a` and `b` by the value of `d`.
 pub fn f32_lerp(a: f32, b: f32, d: f32) -> f32 {
@@ -46,6 +50,19 @@ fn compute_overlap(min_a: f32, max_a: f32, min_b: f32, max_b: f32) -> f32 {
     (size_a + size_b) - (max - min)
 }
 
+/// Returns the `Text` entity for a widget, which is either the widget itself or its first child with a `Text`.
+pub(crate) fn find_text_entity<D: QueryData, F: QueryFilter>(
+    entity: Entity,
+    children: Option<&Children>,
+    text_query: &Query<D, F>,
+) -> Option<Entity> {
+    if text_query.contains(entity) {
+        Some(entity)
+    } else {
+        children.and_then(|children| children.iter().find(|e| text_query.contains(*e)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
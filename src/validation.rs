@@ -0,0 +1,268 @@
+use bevy::{
+    ecs::system::SystemState,
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+
+use crate::{
+    components::*, resources::UiNavState, spatial_map::UiSpatialMap, types::UiNavDirection,
+};
+
+/// A movement from `from` in `direction` that reaches `to`, while the opposite movement from `to` does not return to
+/// `from`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NavOneWayEdge {
+    pub from: Entity,
+    pub to: Entity,
+    pub direction: UiNavDirection,
+}
+
+/// Issues found in the navigation graphs of the menus by [`validate_nav_graph`].
+///
+/// Each menu's graph has an edge for every Up, Down, Left and Right movement between its focusables, and is only
+/// built from focusables that can receive focus, so disabled, hidden and mouse-only focusables are ignored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct NavGraphReport {
+    /// Focusables that cannot be reached by movement from the initial focus of their menu, with their menu
+    pub unreachable: Vec<(Entity, Entity)>,
+    /// Movements that cannot be reversed
+    pub one_way_edges: Vec<NavOneWayEdge>,
+    /// Focusables without a `NavMenu` in their ancestors, excluding mouse-only focusables
+    pub without_menu: Vec<Entity>,
+}
+
+impl NavGraphReport {
+    /// Returns whether no issue was found.
+    pub fn is_ok(&self) -> bool {
+        self.unreachable.is_empty() && self.one_way_edges.is_empty() && self.without_menu.is_empty()
+    }
+}
+
+type ValidationQueries<'w, 's> = (
+    Query<'w, 's, (Entity, &'static NavMenu)>,
+    Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Focusable,
+            &'static ComputedNode,
+            &'static GlobalTransform,
+            &'static InheritedVisibility,
+        ),
+    >,
+    Res<'w, UiNavState>,
+);
+
+/// Builds the navigation graph of each [`NavMenu`] from the current layout and reports the issues found.
+///
+/// This is meant for headless tests that check a menu layout. Focusables are assigned to their menu during `Update`,
+/// so run at least one update after spawning them.
+pub fn validate_nav_graph(world: &mut World) -> NavGraphReport {
+    let mut state = SystemState::<ValidationQueries>::new(world);
+    let (menu_query, query, nav_state) = state.get(world);
+    nav_graph_report(&menu_query, &query, &nav_state)
+}
+
+fn nav_graph_report(
+    menu_query: &Query<(Entity, &NavMenu)>,
+    query: &Query<(
+        Entity,
+        &Focusable,
+        &ComputedNode,
+        &GlobalTransform,
+        &InheritedVisibility,
+    )>,
+    nav_state: &UiNavState,
+) -> NavGraphReport {
+    let spatial_map = UiSpatialMap::new(menu_query, query, nav_state);
+    let mut report = NavGraphReport::default();
+
+    // collect the edges of every focusable in a menu
+    let mut edges = HashMap::<Entity, Vec<(UiNavDirection, Entity)>>::new();
+    for (entity, _) in spatial_map
+        .focusables()
+        .filter(|(_, focus_node)| focus_node.menu.is_some())
+    {
+        let targets = UiNavDirection::CARDINAL
+            .into_iter()
+            .filter_map(|direction| {
+                let trace = spatial_map.trace_movement(entity, direction)?;
+                Some((direction, trace.target()?))
+            })
+            .collect();
+        edges.insert(entity, targets);
+    }
+
+    for (&from, targets) in edges.iter() {
+        for &(direction, to) in targets {
+            let is_reversible = edges
+                .get(&to)
+                .is_some_and(|back| back.contains(&(direction.opposite(), from)));
+            if !is_reversible {
+                report.one_way_edges.push(NavOneWayEdge {
+                    from,
+                    to,
+                    direction,
+                });
+            }
+        }
+    }
+
+    // walk each menu's graph from its initial focus
+    for (menu, _) in menu_query.iter() {
+        let Some(initial) = spatial_map.initial_focusable(Some(menu)) else {
+            continue;
+        };
+        let mut reached = HashSet::from([initial]);
+        let mut stack = vec![initial];
+        while let Some(entity) = stack.pop() {
+            for &(_, target) in edges.get(&entity).into_iter().flatten() {
+                if reached.insert(target) {
+                    stack.push(target);
+                }
+            }
+        }
+        report.unreachable.extend(
+            spatial_map
+                .focusables()
+                .filter(|(entity, focus_node)| {
+                    focus_node.menu == Some(menu) && !reached.contains(entity)
+                })
+                .map(|(entity, _)| (entity, menu)),
+        );
+    }
+
+    report.without_menu = query
+        .iter()
+        .filter(|(_, focusable, ..)| focusable.menu.is_none() && !focusable.is_mouse_only)
+        .map(|(entity, ..)| entity)
+        .collect();
+
+    // sort the issues, since the focusables are not stored in order
+    report.unreachable.sort();
+    report
+        .one_way_edges
+        .sort_by_key(|edge| (edge.from, edge.direction as u8, edge.to));
+    report.without_menu.sort();
+    report
+}
+
+/// System that logs a warning whenever the issues found by [`validate_nav_graph`] change, while
+/// [`UiNavSettings::warn_invalid_nav_graphs`] is enabled.
+///
+/// [`UiNavSettings::warn_invalid_nav_graphs`]: crate::prelude::UiNavSettings::warn_invalid_nav_graphs
+#[allow(clippy::type_complexity)]
+pub(crate) fn warn_invalid_nav_graphs(
+    menu_query: Query<(Entity, &NavMenu)>,
+    query: Query<(
+        Entity,
+        &Focusable,
+        &ComputedNode,
+        &GlobalTransform,
+        &InheritedVisibility,
+    )>,
+    nav_state: Res<UiNavState>,
+    mut last_report: Local<NavGraphReport>,
+) {
+    let report = nav_graph_report(&menu_query, &query, &nav_state);
+    if report == *last_report {
+        return;
+    }
+    for (entity, menu) in report.unreachable.iter() {
+        warn!("The `Focusable` {entity} cannot be reached by movement in the `NavMenu` {menu}.");
+    }
+    for edge in report.one_way_edges.iter() {
+        warn!(
+            "Moving {:?} from the `Focusable` {} reaches {}, but moving back does not return to it.",
+            edge.direction, edge.from, edge.to
+        );
+    }
+    for entity in report.without_menu.iter() {
+        warn!("The `Focusable` {entity} does not have a `NavMenu` in its hierarchy.");
+    }
+    *last_report = report;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Spawns a column of two buttons, and a third button at `position`.
+    fn spawn_menu(app: &mut App, position: Vec2) -> (Entity, Vec<Entity>) {
+        let (menu, mut buttons) = spawn_column(app, NavMenu::new(true, false), Vec2::ZERO, 2);
+        buttons.push(
+            app.world_mut()
+                .spawn((
+                    ChildOf(menu),
+                    Focusable::default(),
+                    focus_node(position, Vec2::new(100., 20.)),
+                ))
+                .id(),
+        );
+        (menu, buttons)
+    }
+
+    #[test]
+    fn column_is_valid() {
+        let mut app = test_app();
+        spawn_column(&mut app, NavMenu::new(true, false), Vec2::ZERO, 3);
+        app.update();
+        assert_eq!(
+            validate_nav_graph(app.world_mut()),
+            NavGraphReport::default()
+        );
+    }
+
+    #[test]
+    fn issues_are_reported() {
+        let mut app = test_app();
+        let size = Vec2::new(100., 20.);
+        // the third button is right of the second, so moving between it and the first cannot be reversed
+        let (_, buttons) = spawn_menu(&mut app, Vec2::new(150., 50.));
+        let orphan = app
+            .world_mut()
+            .spawn((Focusable::default(), focus_node(Vec2::new(0., 200.), size)))
+            .id();
+        app.world_mut().spawn((
+            Focusable::default().with_mouse_only(true),
+            focus_node(Vec2::new(0., 300.), size),
+        ));
+        app.update();
+
+        let report = validate_nav_graph(app.world_mut());
+        assert!(!report.is_ok());
+        assert!(report.unreachable.is_empty());
+        assert_eq!(
+            report.one_way_edges,
+            vec![
+                NavOneWayEdge {
+                    from: buttons[0],
+                    to: buttons[2],
+                    direction: UiNavDirection::Right,
+                },
+                NavOneWayEdge {
+                    from: buttons[2],
+                    to: buttons[0],
+                    direction: UiNavDirection::Up,
+                },
+            ]
+        );
+        assert_eq!(report.without_menu, vec![orphan]);
+    }
+
+    #[test]
+    fn overlapping_focusable_is_unreachable() {
+        let mut app = test_app();
+        let (menu, buttons) = spawn_menu(&mut app, Vec2::new(0., 50.));
+        app.update();
+
+        // only one of the overlapping buttons is picked when moving down
+        let report = validate_nav_graph(app.world_mut());
+        assert_eq!(report.unreachable.len(), 1);
+        let (entity, unreachable_menu) = report.unreachable[0];
+        assert!(entity == buttons[1] || entity == buttons[2]);
+        assert_eq!(unreachable_menu, menu);
+    }
+}
@@ -0,0 +1,322 @@
+use bevy::prelude::*;
+
+use crate::{components::*, events::*, types::*};
+
+/// Component for a list with a large number of items, of which only `visible_rows` are spawned as UI nodes.
+///
+/// The list spawns `visible_rows` [`NavVirtualRow`] focusables as its children. Each row is bound to the logical item
+/// at `first_visible + row`. Moving focus past the first or last visible row scrolls the list by one item instead of
+/// leaving it, and the rows are re-bound to their new items. Focus only leaves the list when moving past the first or
+/// last item.
+///
+/// Rows are spawned with a `Node` of `row_height` and no content. Add content to them when they are added, and update
+/// it when a [`UiNavVirtualRowBoundEvent`] is received or the [`NavVirtualRow`] changes. Rows that are not bound to an
+/// item, because there are fewer items than rows, are hidden.
+///
+/// The list must be within a [`NavMenu`], and should lay out its children in a column.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+pub struct NavVirtualList {
+    /// The total number of logical items
+    pub item_count: usize,
+    /// The number of row entities to spawn
+    pub visible_rows: usize,
+    /// The height of each row in logical pixels
+    pub row_height: f32,
+    /// The index of the item bound to the first row
+    pub(crate) first_visible: usize,
+}
+
+impl NavVirtualList {
+    pub fn new(item_count: usize, visible_rows: usize, row_height: f32) -> Self {
+        Self {
+            item_count,
+            visible_rows,
+            row_height,
+            first_visible: 0,
+        }
+    }
+
+    /// Returns the index of the item bound to the first row.
+    pub fn first_visible(&self) -> usize {
+        self.first_visible
+    }
+
+    /// Scrolls the list the least amount needed to make the item at `index` visible.
+    pub fn scroll_to(&mut self, index: usize) {
+        if index < self.first_visible {
+            self.first_visible = index;
+        } else if index >= self.first_visible + self.visible_rows {
+            self.first_visible = index + 1 - self.visible_rows;
+        }
+        self.clamp_first_visible();
+    }
+
+    /// Clamps `first_visible` so that the rows are filled where possible.
+    fn clamp_first_visible(&mut self) {
+        self.first_visible = self
+            .first_visible
+            .min(self.item_count.saturating_sub(self.visible_rows));
+    }
+}
+
+/// Component for a row entity spawned by a [`NavVirtualList`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Debug, PartialEq)]
+#[require(Focusable, NavCaptureMovement)]
+pub struct NavVirtualRow {
+    /// The list this row belongs to
+    pub(crate) list: Entity,
+    /// The position of this row in the list
+    pub(crate) row: usize,
+    /// The index of the item bound to this row
+    pub(crate) index: Option<usize>,
+}
+
+impl NavVirtualRow {
+    /// Returns the list this row belongs to.
+    pub fn list(&self) -> Entity {
+        self.list
+    }
+
+    /// Returns the position of this row in the list.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the index of the item bound to this row, or `None` if the row is not bound.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+}
+
+/// System that spawns, binds and hides the rows of virtual lists when they change.
+pub(crate) fn sync_virtual_list_rows(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut NavVirtualList), Changed<NavVirtualList>>,
+    mut row_query: Query<(
+        Entity,
+        &mut NavVirtualRow,
+        &mut Node,
+        &mut NavCaptureMovement,
+    )>,
+    mut bound_writer: EventWriter<UiNavVirtualRowBoundEvent>,
+) {
+    for (list_entity, mut list) in query.iter_mut() {
+        list.bypass_change_detection().clamp_first_visible();
+
+        // despawn extra rows and spawn missing rows
+        let mut existing = vec![false; list.visible_rows];
+        for (entity, row, ..) in row_query.iter().filter(|(_, r, ..)| r.list == list_entity) {
+            match existing.get_mut(row.row) {
+                Some(exists) => *exists = true,
+                None => commands.entity(entity).despawn(),
+            }
+        }
+        for (row, _) in existing.iter().enumerate().filter(|(_, exists)| !**exists) {
+            let index = list.first_visible + row;
+            let index = (index < list.item_count).then_some(index);
+            let entity = commands
+                .spawn((
+                    ChildOf(list_entity),
+                    NavVirtualRow {
+                        list: list_entity,
+                        row,
+                        index,
+                    },
+                    row_capture(&list, row),
+                    row_node(&list, index),
+                ))
+                .id();
+            bound_writer.write(UiNavVirtualRowBoundEvent {
+                list: list_entity,
+                row: entity,
+                index,
+            });
+        }
+
+        // re-bind existing rows
+        for (entity, mut row, mut node, mut capture) in row_query
+            .iter_mut()
+            .filter(|(_, r, ..)| r.list == list_entity && r.row < list.visible_rows)
+        {
+            let index = list.first_visible + row.row;
+            let index = (index < list.item_count).then_some(index);
+            if row.index != index {
+                row.index = index;
+                bound_writer.write(UiNavVirtualRowBoundEvent {
+                    list: list_entity,
+                    row: entity,
+                    index,
+                });
+            }
+            capture.set_if_neq(row_capture(&list, row.row));
+            let new_node = row_node(&list, index);
+            if node.display != new_node.display || node.height != new_node.height {
+                node.display = new_node.display;
+                node.height = new_node.height;
+            }
+        }
+    }
+}
+
+/// Returns the movement captured by a row. The first and last rows claim vertical movement while the list can be
+/// scrolled further in that direction.
+fn row_capture(list: &NavVirtualList, row: usize) -> NavCaptureMovement {
+    let can_scroll_up = row == 0 && list.first_visible > 0;
+    let can_scroll_down =
+        row + 1 == list.visible_rows && list.first_visible + list.visible_rows < list.item_count;
+    NavCaptureMovement {
+        horizontal: false,
+        vertical: can_scroll_up || can_scroll_down,
+    }
+}
+
+/// Returns the node for a row, which is hidden when it is not bound to an item.
+fn row_node(list: &NavVirtualList, index: Option<usize>) -> Node {
+    Node {
+        width: Val::Percent(100.),
+        height: Val::Px(list.row_height),
+        flex_shrink: 0.,
+        display: if index.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        },
+        ..default()
+    }
+}
+
+/// System that scrolls virtual lists, or moves focus between their rows, when a row claims movement.
+pub(crate) fn handle_virtual_list_movement(
+    mut events: EventReader<UiNavCapturedMovementEvent>,
+    mut list_query: Query<&mut NavVirtualList>,
+    row_query: Query<(Entity, &NavVirtualRow)>,
+    mut nav_request_writer: EventWriter<NavRequest>,
+) {
+    for event in events.read() {
+        let Ok((_, row)) = row_query.get(event.entity) else {
+            continue;
+        };
+        let Ok(mut list) = list_query.get_mut(row.list) else {
+            continue;
+        };
+        let Some(index) = row.index else {
+            continue;
+        };
+        let target = match event.direction {
+            UiNavDirection::Up => index.checked_sub(1),
+            UiNavDirection::Down => Some(index + 1).filter(|i| *i < list.item_count),
+            _ => None,
+        };
+        let Some(target) = target else {
+            continue;
+        };
+
+        if target >= list.first_visible && target < list.first_visible + list.visible_rows {
+            // the target is visible, so move focus to its row
+            let target_row = target - list.first_visible;
+            if let Some((entity, _)) = row_query
+                .iter()
+                .find(|(_, r)| r.list == row.list && r.row == target_row)
+            {
+                nav_request_writer.write(NavRequest::SetFocus {
+                    entity,
+                    interaction_type: UiNavInteractionType::Button,
+                });
+            }
+        } else {
+            // scroll the list, keeping focus on the same row which is re-bound to the target
+            list.scroll_to(target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::keyboard::Key;
+
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Returns the row entities of the list in order.
+    fn rows(app: &mut App) -> Vec<Entity> {
+        let mut rows = app
+            .world_mut()
+            .query::<(Entity, &NavVirtualRow)>()
+            .iter(app.world())
+            .map(|(entity, row)| (row.row, entity))
+            .collect::<Vec<_>>();
+        rows.sort();
+        rows.into_iter().map(|(_, entity)| entity).collect()
+    }
+
+    fn row_index(app: &App, row: Entity) -> Option<usize> {
+        app.world().get::<NavVirtualRow>(row).unwrap().index()
+    }
+
+    #[test]
+    fn scroll_to_keeps_item_visible() {
+        let mut list = NavVirtualList::new(100, 10, 20.);
+        list.scroll_to(15);
+        assert_eq!(list.first_visible(), 6);
+        list.scroll_to(3);
+        assert_eq!(list.first_visible(), 3);
+        list.scroll_to(500);
+        assert_eq!(list.first_visible(), 90);
+    }
+
+    #[test]
+    fn movement_past_the_viewport_scrolls_rows() {
+        let mut app = test_app();
+        let mut list = Entity::PLACEHOLDER;
+        app.world_mut()
+            .spawn(NavMenu::default().prioritized())
+            .with_children(|p| {
+                list = p.spawn(NavVirtualList::new(100, 3, 20.)).id();
+            });
+        app.update();
+
+        // lay out the rows as the UI layout systems would
+        let rows = rows(&mut app);
+        assert_eq!(rows.len(), 3);
+        for (i, row) in rows.iter().enumerate() {
+            app.world_mut().entity_mut(*row).insert(focus_node(
+                Vec2::new(0., 20. * i as f32),
+                Vec2::new(100., 20.),
+            ));
+        }
+        app.world_mut().send_event(NavRequest::SetFocus {
+            entity: rows[0],
+            interaction_type: UiNavInteractionType::Manual,
+        });
+        app.update();
+        assert_eq!(focused(&mut app), Some(rows[0]));
+        assert_eq!(row_index(&app, rows[2]), Some(2));
+
+        // moving within the viewport moves focus
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(focused(&mut app), Some(rows[2]));
+
+        // moving past the last row scrolls the list
+        clear_events::<UiNavVirtualRowBoundEvent>(&mut app);
+        tap_key(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+        assert_eq!(focused(&mut app), Some(rows[2]));
+        assert_eq!(
+            app.world()
+                .get::<NavVirtualList>(list)
+                .unwrap()
+                .first_visible(),
+            1
+        );
+        assert_eq!(row_index(&app, rows[0]), Some(1));
+        assert_eq!(row_index(&app, rows[2]), Some(3));
+        assert_eq!(read_events::<UiNavVirtualRowBoundEvent>(&app).len(), 3);
+
+        // moving up from a row that claims movement moves focus to the previous row
+        tap_key(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+        assert_eq!(focused(&mut app), Some(rows[1]));
+        assert_eq!(row_index(&app, rows[1]), Some(2));
+    }
+}